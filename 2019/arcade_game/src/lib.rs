@@ -0,0 +1,128 @@
+use intcode::program::{Int, Program};
+use intcode::screen::Screen;
+use intcode::Part;
+use std::sync::mpsc::channel;
+use std::thread;
+
+pub const EMPTY: Int = 0;
+pub const WALL: Int = 1;
+pub const BLOCK: Int = 2;
+pub const PADDLE: Int = 3;
+pub const BALL: Int = 4;
+
+/// Keeps the state of the breakout board and plays it by following the ball with the paddle
+#[derive(Debug, Default)]
+pub struct Arcade {
+    tiles: Screen,
+    score: Int,
+    x_paddle: Int,
+    draws: Vec<(Int, Int, Int)>, // every tile drawn by the program, in order
+    moves: Vec<(usize, Int)>,    // (frame, joystick), frame being the number of draws before it
+}
+
+impl Arcade {
+    pub fn new() -> Self {
+        Arcade::default()
+    }
+
+    /// Handles one output triplet of the program and returns the joystick position when a
+    /// decision is needed, which happens every time the ball is drawn
+    pub fn draw(&mut self, x: Int, y: Int, tile: Int) -> Option<Int> {
+        if (x, y) == (-1, 0) {
+            self.score = tile;
+            return None;
+        }
+        self.tiles.set(x, y, tile);
+        self.draws.push((x, y, tile));
+        match tile {
+            PADDLE => self.x_paddle = x,
+            BALL => {
+                let joystick = (x - self.x_paddle).signum();
+                self.moves.push((self.draws.len(), joystick));
+                return Some(joystick);
+            }
+            _ => (),
+        }
+        None
+    }
+
+    pub fn score(&self) -> Int {
+        self.score
+    }
+
+    pub fn blocks(&self) -> usize {
+        self.tiles.values().filter(|&t| t == BLOCK).count()
+    }
+
+    /// Every `(frame, joystick)` decision taken so far
+    pub fn moves(&self) -> &[(usize, Int)] {
+        &self.moves
+    }
+
+    /// The board as it was when move `n` was decided, `None` if there is no such move
+    pub fn render_frame(&self, n: usize) -> Option<String> {
+        let &(frame, _) = self.moves.get(n)?;
+        let tiles: Screen = self.draws[..frame]
+            .iter()
+            .map(|&(x, y, tile)| ((x, y), tile))
+            .collect();
+        Some(paint(&tiles))
+    }
+
+    /// The board as it is now
+    pub fn render(&self) -> String {
+        paint(&self.tiles)
+    }
+}
+
+/// Draws the tiles row by row, two chars per tile
+pub fn paint(tiles: &Screen) -> String {
+    tiles.render_str(|tile| match tile {
+        WALL => "██",
+        BLOCK => "▒▒",
+        PADDLE => "▔▔",
+        BALL => "()",
+        _ => "  ",
+    })
+}
+
+/// Number of blocks on the screen for part one, score after breaking them all for part two
+pub fn solve(data: &[Int], part: Part) -> Int {
+    match part {
+        Part::One => run(data.to_vec()).blocks() as Int,
+        Part::Two => play(data).score(),
+    }
+}
+
+/// Plays the game with free coins until the program halts
+pub fn play(data: &[Int]) -> Arcade {
+    let mut data = data.to_vec();
+    data[0] = 2;
+    run(data)
+}
+
+/// Runs the program driving the joystick until it halts
+fn run(data: Vec<Int>) -> Arcade {
+    let (output_sender, output_receiver) = channel();
+    let (input_sender, input_receiver) = channel();
+
+    let prog_thread = thread::spawn(move || {
+        let mut prog = Program::from_vec(data, input_receiver, output_sender);
+        prog.run();
+    });
+
+    let mut arcade = Arcade::new();
+    let mut triplet = vec![];
+    while let Ok(out) = output_receiver.recv() {
+        triplet.push(out);
+        if triplet.len() == 3 {
+            if let Some(joystick) = arcade.draw(triplet[0], triplet[1], triplet[2]) {
+                // the program may halt right after drawing the last ball
+                input_sender.send(joystick).ok();
+            }
+            triplet.clear();
+        }
+    }
+    prog_thread.join().unwrap();
+    arcade
+}
@@ -0,0 +1,45 @@
+extern crate arcade_game;
+use arcade_game::{play, solve, Arcade, BALL, BLOCK, PADDLE, WALL};
+use intcode::{get_data_from_path, Part};
+
+#[test]
+fn test_moves() {
+    let mut arcade = Arcade::new();
+    assert_eq!(arcade.draw(0, 0, WALL), None);
+    assert_eq!(arcade.draw(1, 0, BLOCK), None);
+    assert_eq!(arcade.draw(1, 2, PADDLE), None);
+    assert_eq!(arcade.draw(0, 1, BALL), Some(-1));
+    assert_eq!(arcade.draw(-1, 0, 12), None);
+    assert_eq!(arcade.draw(2, 2, PADDLE), None);
+    assert_eq!(arcade.draw(2, 1, BALL), Some(0));
+    assert_eq!(arcade.moves(), &[(4, -1), (6, 0)]);
+    assert_eq!(arcade.score(), 12);
+}
+
+#[test]
+fn test_render_frame() {
+    let mut arcade = Arcade::new();
+    arcade.draw(0, 0, WALL);
+    arcade.draw(1, 1, PADDLE);
+    arcade.draw(0, 1, BALL);
+    arcade.draw(0, 1, 0);
+    arcade.draw(1, 0, BALL);
+    assert_eq!(arcade.render_frame(0), Some("██  \n()▔▔\n".to_string()));
+    assert_eq!(arcade.render_frame(1), Some("██()\n  ▔▔\n".to_string()));
+    assert_eq!(arcade.render_frame(2), None);
+    assert_eq!(arcade.render(), arcade.render_frame(1).unwrap());
+}
+
+#[test]
+fn test_input() {
+    let arcade = play(&get_data_from_path("data/input.txt"));
+    assert_eq!(arcade.blocks(), 0);
+    assert_eq!(arcade.score(), 15957);
+}
+
+#[test]
+fn test_part_one() {
+    let data = get_data_from_path("data/input.txt");
+    // part two is the game played by test_input
+    assert_eq!(solve(&data, Part::One), 324);
+}
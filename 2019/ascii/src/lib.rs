@@ -0,0 +1,70 @@
+use intcode::io::{AsciiReceiver, AsciiSender};
+use intcode::program::{Int, Program};
+use intcode::screen::Screen;
+use intcode::Part;
+
+/// Main routine and functions moving the robot over the whole scaffold of `data/input.txt`,
+/// worked out by hand from the camera view
+pub const ROUTINE: &str =
+    "A,A,B,C,A,C,A,B,C,B\nR,12,L,8,R,6\nR,12,L,6,R,6,R,8,R,6\nL,8,R,8,R,6,R,12\nn\n";
+
+/// Runs the program feeding it the lines of `input`, returning what it printed
+fn run_ascii(data: &[Int], input: &str) -> AsciiSender {
+    let mut receiver = AsciiReceiver::new();
+    for line in input.lines() {
+        receiver.push_line(line);
+    }
+    let mut prog = Program::new(data, receiver, AsciiSender::new());
+    prog.run();
+    prog.peak_output().clone()
+}
+
+/// What the cameras show, one char per cell
+pub fn camera_view(data: &[Int]) -> String {
+    let output = run_ascii(data, "");
+    output.log().to_owned() + output.pending()
+}
+
+/// Sum of the alignment parameters, x times y, of the scaffold intersections in the view
+pub fn alignment_sum(view: &str) -> usize {
+    let screen: Screen = view
+        .lines()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .map(move |(x, c)| ((x as Int, y as Int), c as Int))
+        })
+        .collect();
+    let scaffold = |(x, y): (Int, Int)| screen.get(x, y) == Some('#' as Int);
+    screen
+        .iter()
+        .filter(|&(pos, _)| scaffold(pos))
+        .filter(|&((x, y), _)| {
+            scaffold((x, y - 1))
+                && scaffold((x, y + 1))
+                && scaffold((x + 1, y))
+                && scaffold((x - 1, y))
+        })
+        .map(|((x, y), _)| (x * y) as usize)
+        .sum()
+}
+
+/// Dust collected by the robot walking the scaffold with `routine`, the last output of the
+/// program once woken up
+pub fn collect_dust(data: &[Int], routine: &str) -> Int {
+    let mut data = data.to_vec();
+    data[0] = 2;
+    run_ascii(&data, routine)
+        .last_big_value()
+        .expect("no dust reported")
+}
+
+/// Sum of the alignment parameters for part one, dust collected with `ROUTINE` for part two.
+/// The parts are independent, the routine was found by reading the view of part one
+pub fn solve(data: &[Int], part: Part) -> Int {
+    match part {
+        Part::One => alignment_sum(&camera_view(data)) as Int,
+        Part::Two => collect_dust(data, ROUTINE),
+    }
+}
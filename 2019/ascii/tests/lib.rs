@@ -0,0 +1,24 @@
+extern crate ascii;
+use ascii::{alignment_sum, camera_view, solve};
+use intcode::{get_data_from_path, Part};
+
+#[test]
+fn test_alignment_sum() {
+    let view = "..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..
+";
+    assert_eq!(alignment_sum(view), 76);
+}
+
+#[test]
+fn test_input() {
+    let data = get_data_from_path("data/input.txt");
+    assert!(camera_view(&data).contains('^'));
+    assert_eq!(solve(&data, Part::One), 5056);
+    assert_eq!(solve(&data, Part::Two), 942367);
+}
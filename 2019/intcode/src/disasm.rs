@@ -0,0 +1,74 @@
+use crate::opcode::{decode_at, Mode, Opcode};
+use crate::program::Int;
+
+fn mnemonic(op: &Opcode) -> &'static str {
+    match op {
+        Opcode::Add(..) => "ADD",
+        Opcode::Multiply(..) => "MUL",
+        Opcode::JumpIfTrue(..) => "JNZ",
+        Opcode::JumpIfFalse(..) => "JZ",
+        Opcode::LessThan(..) => "LT",
+        Opcode::Equals(..) => "EQ",
+        Opcode::SetRelBase(_) => "ARB",
+        Opcode::Input(_) => "IN",
+        Opcode::Output(_) => "OUT",
+        Opcode::Halt => "HALT",
+    }
+}
+
+fn modes(op: &Opcode) -> Vec<&Mode> {
+    match op {
+        Opcode::Add(m0, m1, m2)
+        | Opcode::Multiply(m0, m1, m2)
+        | Opcode::LessThan(m0, m1, m2)
+        | Opcode::Equals(m0, m1, m2) => vec![m0, m1, m2],
+        Opcode::JumpIfTrue(m0, m1) | Opcode::JumpIfFalse(m0, m1) => vec![m0, m1],
+        Opcode::SetRelBase(m0) | Opcode::Input(m0) | Opcode::Output(m0) => vec![m0],
+        Opcode::Halt => vec![],
+    }
+}
+
+/// `[n]` reads address n, `n` is the literal and `[rb+n]` is relative to the rel base
+fn operand(mode: &Mode, num: Int) -> String {
+    match mode {
+        Mode::Position => format!("[{}]", num),
+        Mode::Inmediate => num.to_string(),
+        Mode::Relative => format!("[rb{:+}]", num),
+    }
+}
+
+/// One line per instruction starting in `start..end`, with its address, mnemonic and operands.
+/// Words that are not instructions, or whose parameters run past the memory, are shown as
+/// `DATA` and skipped one by one
+pub fn disassemble(mem: &[Int], start: usize, end: usize) -> String {
+    let start = start.min(mem.len());
+    disassemble_window(&mem[start..], start, end.saturating_sub(start))
+}
+
+/// Same as `disassemble` over the first `len` words of `window`, which holds the memory from
+/// address `base` on
+pub(crate) fn disassemble_window(window: &[Int], base: usize, len: usize) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < len.min(window.len()) {
+        let addr = base + i;
+        match decode_at(window, i) {
+            Ok((op, op_len)) if i + op_len <= window.len() => {
+                let operands: Vec<String> = modes(&op)
+                    .into_iter()
+                    .zip(&window[i + 1..i + op_len])
+                    .map(|(mode, &num)| operand(mode, num))
+                    .collect();
+                let line = format!("{:>5}: {} {}", addr, mnemonic(&op), operands.join(", "));
+                out.push_str(line.trim_end());
+                i += op_len;
+            }
+            _ => {
+                out.push_str(&format!("{:>5}: DATA {}", addr, window[i]));
+                i += 1;
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
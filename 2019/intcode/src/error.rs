@@ -0,0 +1,48 @@
+use crate::opcode::DecodeError;
+use std::error::Error;
+use std::fmt;
+
+/// Ways a program can fail instead of running to its halt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntcodeError {
+    /// Strict mode caught a write to `addr`, part of the instruction about to run
+    SelfModifyingCode { addr: usize },
+    /// A write went past the configured maximum address
+    AddressTooLarge { addr: usize },
+    /// The pointer left the memory the program has
+    PointerOutOfBounds { addr: usize },
+    /// The pointer reached something that is not an instruction
+    Decode(DecodeError),
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntcodeError::SelfModifyingCode { addr } => {
+                write!(f, "write to {} modifies the next instruction", addr)
+            }
+            IntcodeError::AddressTooLarge { addr } => {
+                write!(f, "write to {} is past the maximum address", addr)
+            }
+            IntcodeError::PointerOutOfBounds { addr } => {
+                write!(f, "pointer {} is out of the memory", addr)
+            }
+            IntcodeError::Decode(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for IntcodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IntcodeError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for IntcodeError {
+    fn from(e: DecodeError) -> Self {
+        IntcodeError::Decode(e)
+    }
+}
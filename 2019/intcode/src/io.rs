@@ -0,0 +1,259 @@
+use crate::program::{Int, ProgReceiver, ProgSender};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// Stands for both ends of a program that does no I/O, discarding any output. It never has
+/// input, so `run_until_blocked` pauses with `NeedsInput` and `try_run` asks stdin
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoIo;
+
+impl ProgSender for NoIo {
+    fn put(&mut self, _num: Int) {}
+}
+
+impl ProgReceiver for NoIo {
+    fn get(&mut self) -> Option<Int> {
+        None
+    }
+}
+
+/// Collects the output, optionally holding at most `max` values so a driver can drain it in
+/// between runs
+#[derive(Debug, Clone, Default)]
+pub struct VecOutput {
+    values: Vec<Int>,
+    max: Option<usize>,
+}
+
+impl VecOutput {
+    pub fn new() -> Self {
+        VecOutput::default()
+    }
+
+    pub fn with_max(max: usize) -> Self {
+        VecOutput {
+            values: vec![],
+            max: Some(max),
+        }
+    }
+
+    pub fn values(&self) -> &[Int] {
+        &self.values
+    }
+
+    /// Takes everything collected so far
+    pub fn drain(&mut self) -> Vec<Int> {
+        std::mem::take(&mut self.values)
+    }
+}
+
+impl ProgSender for VecOutput {
+    fn put(&mut self, num: Int) {
+        self.values.push(num);
+    }
+
+    fn is_full(&self) -> bool {
+        self.max.is_some_and(|max| self.values.len() >= max)
+    }
+}
+
+/// A queue shared by its clones, so the output of a program can be the input of another one,
+/// or of itself
+#[derive(Debug, Clone, Default)]
+pub struct QueueIo(Rc<RefCell<VecDeque<Int>>>);
+
+impl QueueIo {
+    pub fn new() -> Self {
+        QueueIo::default()
+    }
+
+    pub fn push(&self, num: Int) {
+        self.0.borrow_mut().push_back(num);
+    }
+
+    pub fn pop(&self) -> Option<Int> {
+        self.0.borrow_mut().pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    /// The queued values, first to pop first
+    pub fn values(&self) -> Vec<Int> {
+        self.0.borrow().iter().cloned().collect()
+    }
+}
+
+impl ProgSender for QueueIo {
+    fn put(&mut self, num: Int) {
+        self.push(num);
+    }
+}
+
+impl ProgReceiver for QueueIo {
+    fn get(&mut self) -> Option<Int> {
+        self.pop()
+    }
+}
+
+/// Sending end of a channel between programs running on different threads
+#[derive(Debug, Clone)]
+pub struct ChannelSender(pub Sender<Int>);
+
+/// Receiving end of a channel, waits for a value and gives `None` once every sender is gone.
+/// Meant for a program running on its own thread with `run`, as `run_until_blocked` would
+/// block the thread instead of pausing with `NeedsInput`
+#[derive(Debug)]
+pub struct ChannelReceiver(pub Receiver<Int>);
+
+/// Receiving end of a channel that never waits, `None` when no value has been sent yet. Meant
+/// for programs driven by `run_until_blocked` or `run_until_event`, so a single thread can take
+/// turns between them
+#[derive(Debug)]
+pub struct TryChannelReceiver(pub Receiver<Int>);
+
+pub fn channel() -> (ChannelSender, ChannelReceiver) {
+    let (s, r) = mpsc::channel();
+    (ChannelSender(s), ChannelReceiver(r))
+}
+
+/// Same as `channel` with a receiver that does not wait for values
+pub fn try_channel() -> (ChannelSender, TryChannelReceiver) {
+    let (s, r) = mpsc::channel();
+    (ChannelSender(s), TryChannelReceiver(r))
+}
+
+impl ProgSender for ChannelSender {
+    fn put(&mut self, num: Int) {
+        // nobody listening is the receiver's problem, the program can go on
+        self.0.send(num).ok();
+    }
+}
+
+impl ProgReceiver for ChannelReceiver {
+    fn get(&mut self) -> Option<Int> {
+        self.0.recv().ok()
+    }
+}
+
+impl ProgReceiver for TryChannelReceiver {
+    fn get(&mut self) -> Option<Int> {
+        self.0.try_recv().ok()
+    }
+}
+
+/// Gives the values in order and then `None`
+#[derive(Debug, Clone, Default)]
+pub struct ConstInput {
+    values: Vec<Int>,
+    next: usize,
+}
+
+impl ConstInput {
+    pub fn new(values: &[Int]) -> Self {
+        ConstInput {
+            values: values.to_vec(),
+            next: 0,
+        }
+    }
+}
+
+impl ProgReceiver for ConstInput {
+    fn get(&mut self) -> Option<Int> {
+        let num = self.values.get(self.next).cloned();
+        self.next += 1;
+        num
+    }
+}
+
+/// Turns the output of an ASCII program into text: complete lines go to the log, values out
+/// of the ASCII range are kept apart as they are the actual answers
+#[derive(Debug, Clone, Default)]
+pub struct AsciiSender {
+    log: String,
+    line: String,
+    last_big_value: Option<Int>,
+}
+
+impl AsciiSender {
+    pub fn new() -> Self {
+        AsciiSender::default()
+    }
+
+    /// Every complete line received so far, newlines included
+    pub fn log(&self) -> &str {
+        &self.log
+    }
+
+    /// The start of a line still waiting for its newline, like a prompt
+    pub fn pending(&self) -> &str {
+        &self.line
+    }
+
+    /// Takes the log, leaving it empty
+    pub fn take_log(&mut self) -> String {
+        std::mem::take(&mut self.log)
+    }
+
+    pub fn last_big_value(&self) -> Option<Int> {
+        self.last_big_value
+    }
+}
+
+impl ProgSender for AsciiSender {
+    fn put(&mut self, num: Int) {
+        match num {
+            0..=127 => {
+                let c = num as u8 as char;
+                self.line.push(c);
+                if c == '\n' {
+                    self.log.push_str(&self.line);
+                    self.line.clear();
+                }
+            }
+            _ => self.last_big_value = Some(num),
+        }
+    }
+}
+
+/// Feeds text commands to an ASCII program, one char per input
+#[derive(Debug, Clone, Default)]
+pub struct AsciiReceiver {
+    queue: VecDeque<Int>,
+}
+
+impl AsciiReceiver {
+    pub fn new() -> Self {
+        AsciiReceiver::default()
+    }
+
+    /// Queues `line`, adding the newline that ends every command if it is missing
+    pub fn push_line(&mut self, line: &str) {
+        self.queue.extend(line.bytes().map(Int::from));
+        if !line.ends_with('\n') {
+            self.queue.push_back(Int::from(b'\n'));
+        }
+    }
+
+    /// Number of chars still to be read
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl ProgReceiver for AsciiReceiver {
+    fn get(&mut self) -> Option<Int> {
+        self.queue.pop_front()
+    }
+}
@@ -1,11 +1,48 @@
-mod opcode;
+pub mod disasm;
+pub mod error;
+pub mod io;
+pub mod opcode;
 pub mod program;
+pub mod screen;
+pub mod solution_7;
 pub mod solution_7a;
 pub mod solution_7b;
+pub mod turtle;
 
-use program::{Int, ProgReceiver, ProgSender, Program};
-use std::env;
+use program::{Int, ProgReceiver, ProgSender};
+use std::collections::VecDeque;
 use std::fs::read_to_string;
+use std::str::FromStr;
+
+/// Which half of a day to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The parts asked for with `--part N`, both of them when there are no arguments
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Vec<Part>, String> {
+        match (args.next(), args.next(), args.next()) {
+            (None, _, _) => Ok(vec![Part::One, Part::Two]),
+            (Some(flag), Some(part), None) if flag == "--part" => Ok(vec![part.parse()?]),
+            _ => Err("usage: [--part 1|2]".to_string()),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("bad part {}, expected 1 or 2", s)),
+        }
+    }
+}
 
 impl ProgSender for &mut Vec<Int> {
     fn put(&mut self, num: Int) {
@@ -19,6 +56,12 @@ impl ProgReceiver for &mut Vec<Int> {
     }
 }
 
+impl ProgReceiver for VecDeque<Int> {
+    fn get(&mut self) -> Option<Int> {
+        self.pop_front()
+    }
+}
+
 pub fn get_data_from_path(path: &str) -> Vec<Int> {
     get_data_from_str(&read_to_string(path).expect("bad input"))
 }
@@ -1,15 +1,15 @@
 use intcode::program::{Int, Program};
-use intcode::{get_data_from_path, solution_7a, solution_7b};
-use std::env;
+use intcode::{get_data_from_path, solution_7, solution_7a, solution_7b, Part};
+use std::{env, process};
 
-fn simple_run(data: &Vec<Int>, debug: bool) {
+fn simple_run(data: &[Int], debug: bool) {
     let mut input = vec![];
     let mut output = vec![];
-    let mut program = Program::new(&data, &mut input, &mut output);
+    let mut program = Program::new(data, &mut input, &mut output);
     if debug {
         program.run_debug_mode();
     } else {
-        program.run()
+        program.run();
     }
     println!("OUTPUT: {:?}", output);
 }
@@ -23,6 +23,15 @@ fn main() {
             "7a" => println!("{:?}", solution_7a::run_solution(&data, false)),
             "7a_dbg" => println!("{:?}", solution_7a::run_solution(&data, true)),
             "7b" => println!("{:?}", solution_7b::run_solution(data, false)),
+            "7" => {
+                let parts = Part::from_args(args).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    process::exit(2)
+                });
+                for part in parts {
+                    println!("{:?} {}", part, solution_7::solve(&data, part));
+                }
+            }
             "dbg" => simple_run(&data, true),
             _ => panic!("what?"),
         },
@@ -1,4 +1,6 @@
 use crate::program::Int;
+use std::error::Error;
+use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum Mode {
@@ -22,22 +24,23 @@ pub enum Opcode {
 }
 
 impl Mode {
-    pub fn from_num(num: Int) -> Self {
+    pub fn from_num(num: Int) -> Option<Self> {
         match num {
-            0 => Mode::Position,
-            1 => Mode::Inmediate,
-            2 => Mode::Relative,
-            _ => panic!("bad mode from num {}", num),
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Inmediate),
+            2 => Some(Mode::Relative),
+            _ => None,
         }
     }
 }
-pub fn from_num(num: Int) -> Opcode {
+/// Decodes the opcode and modes packed in `num`, `None` if either is unknown
+pub fn from_num(num: Int) -> Option<Opcode> {
     let aux = num.div_euclid(100);
-    let m0 = Mode::from_num(aux.rem_euclid(10));
+    let m0 = Mode::from_num(aux.rem_euclid(10))?;
     let aux2 = aux.div_euclid(10);
-    let m1 = Mode::from_num(aux2.rem_euclid(10));
-    let m2 = Mode::from_num(aux2.div_euclid(10));
-    match num.rem_euclid(100) {
+    let m1 = Mode::from_num(aux2.rem_euclid(10))?;
+    let m2 = Mode::from_num(aux2.div_euclid(10))?;
+    let code = match num.rem_euclid(100) {
         1 => Opcode::Add(m0, m1, m2),
         2 => Opcode::Multiply(m0, m1, m2),
         3 => Opcode::Input(m0),
@@ -48,6 +51,45 @@ pub fn from_num(num: Int) -> Opcode {
         8 => Opcode::Equals(m0, m1, m2),
         9 => Opcode::SetRelBase(m0),
         99 => Opcode::Halt,
-        _ => panic!("bad code from num {}", num),
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Why the cell at `addr` is not an instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    OutOfBounds { addr: usize },
+    Invalid { addr: usize, num: Int },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::OutOfBounds { addr } => write!(f, "address {} is out of bounds", addr),
+            DecodeError::Invalid { addr, num } => {
+                write!(f, "{} at address {} is not an instruction", num, addr)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Decodes the instruction at `addr` of a raw program, returning it with its length
+pub fn decode_at(mem: &[Int], addr: usize) -> Result<(Opcode, usize), DecodeError> {
+    let num = *mem.get(addr).ok_or(DecodeError::OutOfBounds { addr })?;
+    let op = from_num(num).ok_or(DecodeError::Invalid { addr, num })?;
+    Ok((op, instruction_len(num)))
+}
+
+/// Number of memory cells taken by the instruction encoded as `num`, parameters included.
+/// Unknown codes count as a single cell
+pub fn instruction_len(num: Int) -> usize {
+    match num.rem_euclid(100) {
+        1 | 2 | 7 | 8 => 4,
+        5 | 6 => 3,
+        3 | 4 | 9 => 2,
+        _ => 1,
     }
 }
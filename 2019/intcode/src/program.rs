@@ -1,12 +1,20 @@
+use crate::disasm::disassemble_window;
+use crate::error::IntcodeError;
+use crate::io::NoIo;
 use crate::opcode::*;
 use colored::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::io;
 use std::io::Write;
+use std::ops::Range;
 
 pub type Int = i64;
 
+/// Writes past this address are refused, no AoC program gets close to it
+pub const DEFAULT_MAX_ADDRESS: usize = 4_000_000;
+
+#[derive(Clone)]
 pub struct Program<S: ProgSender, R: ProgReceiver> {
     mem: Vec<Int>,
     aux_mem: HashMap<usize, Int>, // holds whatever does not fit in mem
@@ -14,10 +22,77 @@ pub struct Program<S: ProgSender, R: ProgReceiver> {
     input: R,
     output: S,
     rel_base: Int,
+    last_op: Option<Opcode>,
+    instructions: u64,
+    inputs: u64,
+    pending_input: Option<Int>, // taken from the receiver or stdin, not yet stored
+    halted: bool,
+    strict: bool,
+    max_address: usize,
+    breakpoints: HashSet<usize>,
+    watchpoints: HashSet<usize>,
+    watch_hit: Option<(usize, Int, Int)>, // (addr, old, new) if the last instruction wrote a watch
+}
+
+/// Why the debugger stopped before the next instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Step,
+    Breakpoint(usize),
+    Watch { addr: usize, old: Int, new: Int },
+}
+
+/// Everything a program is but its I/O and settings, to go back to it or to start other
+/// programs from it
+#[derive(Debug, Clone)]
+pub struct ProgramState {
+    mem: Vec<Int>,
+    aux_mem: HashMap<usize, Int>,
+    pointer: usize,
+    rel_base: Int,
+    last_op: Option<Opcode>,
+    instructions: u64,
+    inputs: u64,
+    pending_input: Option<Int>,
+    halted: bool,
+}
+
+/// The state shown by the debugger, for callers that want to present it themselves
+#[derive(Debug, Clone)]
+pub struct DebugSnapshot {
+    pub pointer: usize,
+    pub rel_base: Int,
+    /// Addresses of `memory`
+    pub window: Range<usize>,
+    pub memory: Vec<Int>,
+    pub last_op: Option<Opcode>,
+}
+
+/// Why a run returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    Halted,
+    OutputBufferFull,
+    NeedsInput,
+}
+
+/// What running a single instruction led to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepResult {
+    Continue,
+    NeedsInput,
+    Output(Int),
+    OutputBufferFull,
+    Halted,
 }
 
 pub trait ProgSender: Debug {
     fn put(&mut self, num: Int);
+
+    /// A full sender pauses the program before its next output
+    fn is_full(&self) -> bool {
+        false
+    }
 }
 
 pub trait ProgReceiver: Debug {
@@ -25,8 +100,14 @@ pub trait ProgReceiver: Debug {
 }
 
 impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
-    pub fn new(data: &Vec<Int>, input: R, output: S) -> Self {
-        let mut mem = data.clone();
+    /// Copies `data` into the memory of the program, use `from_vec` when the data is not
+    /// needed afterwards
+    pub fn new(data: &[Int], input: R, output: S) -> Self {
+        Program::from_vec(data.to_vec(), input, output)
+    }
+
+    /// Takes `mem` as the memory of the program, saving the copy made by `new`
+    pub fn from_vec(mem: Vec<Int>, input: R, output: S) -> Self {
         Program {
             mem,
             pointer: 0,
@@ -34,10 +115,131 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
             output,
             rel_base: 0,
             aux_mem: HashMap::new(),
+            last_op: None,
+            instructions: 0,
+            inputs: 0,
+            pending_input: None,
+            halted: false,
+            strict: false,
+            max_address: DEFAULT_MAX_ADDRESS,
+            breakpoints: HashSet::new(),
+            watchpoints: HashSet::new(),
+            watch_hit: None,
+        }
+    }
+
+    pub fn snapshot(&self) -> ProgramState {
+        ProgramState {
+            mem: self.mem.clone(),
+            aux_mem: self.aux_mem.clone(),
+            pointer: self.pointer,
+            rel_base: self.rel_base,
+            last_op: self.last_op.clone(),
+            instructions: self.instructions,
+            inputs: self.inputs,
+            pending_input: self.pending_input,
+            halted: self.halted,
+        }
+    }
+
+    /// Goes back to `state`, reusing the memory already allocated
+    pub fn restore(&mut self, state: &ProgramState) {
+        self.mem.clone_from(&state.mem);
+        self.aux_mem.clone_from(&state.aux_mem);
+        self.pointer = state.pointer;
+        self.rel_base = state.rel_base;
+        self.last_op = state.last_op.clone();
+        self.instructions = state.instructions;
+        self.inputs = state.inputs;
+        self.pending_input = state.pending_input;
+        self.halted = state.halted;
+        self.watch_hit = None;
+    }
+
+    /// A new program going on from where this one is, with its own I/O
+    pub fn fork<S2: ProgSender, R2: ProgReceiver>(&self, input: R2, output: S2) -> Program<S2, R2> {
+        let state = self.snapshot();
+        Program {
+            mem: state.mem,
+            aux_mem: state.aux_mem,
+            pointer: state.pointer,
+            input,
+            output,
+            rel_base: state.rel_base,
+            last_op: state.last_op,
+            instructions: state.instructions,
+            inputs: state.inputs,
+            pending_input: state.pending_input,
+            halted: state.halted,
+            strict: self.strict,
+            max_address: self.max_address,
+            breakpoints: self.breakpoints.clone(),
+            watchpoints: self.watchpoints.clone(),
+            watch_hit: None,
+        }
+    }
+
+    /// Starts over from `original`, reusing the memory already allocated
+    pub fn reset(&mut self, original: &[Int]) {
+        self.mem.clear();
+        self.mem.extend_from_slice(original);
+        self.aux_mem.clear();
+        self.pointer = 0;
+        self.rel_base = 0;
+        self.last_op = None;
+        self.instructions = 0;
+        self.inputs = 0;
+        self.pending_input = None;
+        self.halted = false;
+        self.watch_hit = None;
+    }
+
+    /// Rejects writes into the instruction that follows the running one, which legitimate
+    /// programs may do on purpose, so it is off by default
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets a breakpoint on `addr`, or clears it if there was one. Returns whether it is set
+    pub fn toggle_breakpoint(&mut self, addr: usize) -> bool {
+        toggle(&mut self.breakpoints, addr)
+    }
+
+    /// Sets a watchpoint on `addr`, or clears it if there was one. Returns whether it is set
+    pub fn toggle_watchpoint(&mut self, addr: usize) -> bool {
+        toggle(&mut self.watchpoints, addr)
+    }
+
+    /// Same as `disasm::disassemble`, stopping at the last address holding data but reading the
+    /// parameters of the last instruction past it like the program does
+    pub fn disassemble(&self, start: usize, end: usize) -> String {
+        let end = end.min(self.last_address() + 1);
+        let start = start.min(end);
+        // the last instruction may have its parameters after end
+        let window = self.debug_snapshot(start..end.saturating_add(3));
+        disassemble_window(&window.memory, start, end - start)
+    }
+
+    /// Why the debugger stops before the next instruction, if it does: a watched address the
+    /// last instruction wrote to, or else a breakpoint on the pointer. A watch hit is only
+    /// reported once
+    pub fn take_stop_reason(&mut self) -> Option<StopReason> {
+        if let Some((addr, old, new)) = self.watch_hit.take() {
+            Some(StopReason::Watch { addr, old, new })
+        } else if self.breakpoints.contains(&self.pointer) {
+            Some(StopReason::Breakpoint(self.pointer))
+        } else {
+            None
         }
     }
+
+    /// Highest address the program may write to, bounding the memory it can take
+    pub fn set_max_address(&mut self, max_address: usize) {
+        self.max_address = max_address;
+    }
+
     /// Dispatchs the corresponding operation and returns the new pointer
-    fn execute(&mut self, code: Opcode) {
+    fn execute(&mut self, code: Opcode) -> Result<(), IntcodeError> {
         match code {
             Opcode::Add(m0, m1, m2) => self.add(m0, m1, m2),
             Opcode::Multiply(m0, m1, m2) => self.multiply(m0, m1, m2),
@@ -60,12 +262,26 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
         }
     }
 
-    fn write(&mut self, p: usize, val: Int) {
+    fn write(&mut self, p: usize, val: Int) -> Result<(), IntcodeError> {
+        if p > self.max_address {
+            return Err(IntcodeError::AddressTooLarge { addr: p });
+        }
+        if self.strict {
+            // writes happen before the pointer moves past the running instruction
+            let next = self.pointer + instruction_len(self.read(self.pointer));
+            if (next..next + instruction_len(self.read(next))).contains(&p) {
+                return Err(IntcodeError::SelfModifyingCode { addr: p });
+            }
+        }
+        if self.watchpoints.contains(&p) {
+            self.watch_hit = Some((p, self.read(p), val));
+        }
         if p < self.mem.len() {
             self.mem[p] = val;
         } else {
             self.aux_mem.insert(p, val);
         }
+        Ok(())
     }
 
     fn get_relative_position(&self, offset_wrt_pointer: usize, m: Mode) -> usize {
@@ -77,49 +293,55 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
         }
     }
 
-    fn add(&mut self, m0: Mode, m1: Mode, m2: Mode) {
+    fn add(&mut self, m0: Mode, m1: Mode, m2: Mode) -> Result<(), IntcodeError> {
         let p = self.get_relative_position(3, m2);
         let val = self.get_param(1, m0) + self.get_param(2, m1);
-        self.write(p, val);
+        self.write(p, val)?;
         self.pointer += 4;
+        Ok(())
     }
 
-    fn multiply(&mut self, m0: Mode, m1: Mode, m2: Mode) {
+    fn multiply(&mut self, m0: Mode, m1: Mode, m2: Mode) -> Result<(), IntcodeError> {
         let p = self.get_relative_position(3, m2);
         let val = self.get_param(1, m0) * self.get_param(2, m1);
-        self.write(p, val);
+        self.write(p, val)?;
         self.pointer += 4;
+        Ok(())
     }
 
-    fn jump_if_true(&mut self, m0: Mode, m1: Mode) {
+    fn jump_if_true(&mut self, m0: Mode, m1: Mode) -> Result<(), IntcodeError> {
         self.pointer = match self.get_param(1, m0) != 0 {
             true => self.get_param(2, m1) as usize,
             false => self.pointer + 3,
-        }
+        };
+        Ok(())
     }
 
-    fn jump_if_false(&mut self, m0: Mode, m1: Mode) {
+    fn jump_if_false(&mut self, m0: Mode, m1: Mode) -> Result<(), IntcodeError> {
         self.pointer = match self.get_param(1, m0) == 0 {
             true => self.get_param(2, m1) as usize,
             false => self.pointer + 3,
-        }
+        };
+        Ok(())
     }
 
-    fn less_than(&mut self, m0: Mode, m1: Mode, m2: Mode) {
+    fn less_than(&mut self, m0: Mode, m1: Mode, m2: Mode) -> Result<(), IntcodeError> {
         let p = self.get_relative_position(3, m2);
         let val = (self.get_param(1, m0) < self.get_param(2, m1)) as Int;
-        self.write(p, val);
+        self.write(p, val)?;
         self.pointer += 4;
+        Ok(())
     }
 
-    fn equals(&mut self, m0: Mode, m1: Mode, m2: Mode) {
+    fn equals(&mut self, m0: Mode, m1: Mode, m2: Mode) -> Result<(), IntcodeError> {
         let p = self.get_relative_position(3, m2);
         let val = (self.get_param(1, m0) == self.get_param(2, m1)) as Int;
-        self.write(p, val);
+        self.write(p, val)?;
         self.pointer += 4;
+        Ok(())
     }
 
-    fn set_rel_base(&mut self, m0: Mode) {
+    fn set_rel_base(&mut self, m0: Mode) -> Result<(), IntcodeError> {
         let literal_num = self.read(self.pointer + 1);
         self.rel_base += match m0 {
             Mode::Inmediate => literal_num,
@@ -127,37 +349,46 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
             Mode::Relative => self.read((self.rel_base + literal_num) as usize),
         };
         self.pointer += 2;
+        Ok(())
     }
 
-    fn input(&mut self, m0: Mode) {
-        // Get the input num from the input field or stdin oherwise
-        let n: Int = match self.input.get() {
-            Some(x) => x,
-            None => {
-                let mut inp = String::new();
-                print!("Input please, human: ");
-                io::stdout().flush().unwrap();
-                io::stdin().read_line(&mut inp).unwrap();
-                let nn = inp.trim().parse();
-                if nn.is_err() {
-                    self.input(m0);
-                    return;
-                }
-                nn.unwrap()
-            }
-        };
+    fn input(&mut self, m0: Mode) -> Result<(), IntcodeError> {
+        // try_step only runs inputs once pending_input holds the value, which is kept until it
+        // is stored so a failed write can be retried
+        let n = self.pending_input.expect("input without a pending value");
         let p = self.get_relative_position(1, m0);
-        self.write(p, n);
+        self.write(p, n)?;
+        self.pending_input = None;
+        self.inputs += 1;
         self.pointer += 2;
+        Ok(())
+    }
+
+    /// Asks stdin for the value of an input, for interactive runs
+    fn prompt_input(&mut self) {
+        loop {
+            let mut inp = String::new();
+            print!("Input please, human: ");
+            io::stdout().flush().unwrap();
+            io::stdin().read_line(&mut inp).unwrap();
+            if let Ok(n) = inp.trim().parse() {
+                self.pending_input = Some(n);
+                return;
+            }
+        }
     }
 
-    fn output(&mut self, m0: Mode) {
+    fn output(&mut self, m0: Mode) -> Result<(), IntcodeError> {
         let out = self.get_param(1, m0);
         self.output.put(out);
         self.pointer += 2;
+        Ok(())
     }
 
-    fn halt(&mut self) {}
+    fn halt(&mut self) -> Result<(), IntcodeError> {
+        self.halted = true;
+        Ok(())
+    }
 
     fn get_param(&mut self, position: usize, inmediate_mode: Mode) -> Int {
         let literal_num = self.read(self.pointer + position);
@@ -176,61 +407,137 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
         &self.output
     }
 
+    /// Lets a driver feed a program paused for input
+    pub fn input_mut(&mut self) -> &mut R {
+        &mut self.input
+    }
+
+    /// Lets a driver drain the output of a paused program
+    pub fn output_mut(&mut self) -> &mut S {
+        &mut self.output
+    }
+
     pub fn peak_mem(&self) -> &[Int] {
         &self.mem
     }
 
-    fn debug(&self, last_code: Opcode) {
+    /// Number of instructions executed so far, counting the final halt
+    pub fn instructions(&self) -> u64 {
+        self.instructions
+    }
+
+    /// Number of values read by input instructions so far
+    pub fn inputs_consumed(&self) -> u64 {
+        self.inputs
+    }
+
+    /// Highest address holding data, either in the program or written past its end
+    pub fn last_address(&self) -> usize {
+        let last_aux = self.aux_mem.keys().max().copied().unwrap_or(0);
+        last_aux.max(self.mem.len().saturating_sub(1))
+    }
+
+    /// Captures the registers, the last executed operation and the memory in `window`, reading
+    /// past the end of the program like the program itself does
+    pub fn debug_snapshot(&self, window: Range<usize>) -> DebugSnapshot {
+        DebugSnapshot {
+            pointer: self.pointer,
+            rel_base: self.rel_base,
+            memory: window.clone().map(|p| self.read(p)).collect(),
+            window,
+            last_op: self.last_op.clone(),
+        }
+    }
+
+    /// Shows the prompt until the user picks how to go on, returning whether to run freely
+    fn debug(&mut self, reason: StopReason) -> bool {
         let dbg = "[Debug] ".green();
-        let mut c: char;
-        let mut inp: String;
-        while {
+        let snapshot = self.debug_snapshot(0..self.mem.len());
+        match reason {
+            StopReason::Step => (),
+            StopReason::Breakpoint(addr) => println!("{}breakpoint at {}", dbg, addr),
+            StopReason::Watch { addr, old, new } => {
+                println!("{}watch {} changed {} -> {}", dbg, addr, old, new)
+            }
+        }
+        loop {
             print!(
                 "{}lastop({:^24}) pointer({:^3}) $ ",
                 dbg,
-                format!("{:?}", last_code),
-                self.pointer
+                format!("{:?}", snapshot.last_op),
+                snapshot.pointer
             );
             io::stdout().flush().unwrap();
-            inp = String::new();
-            io::stdin().read_line(&mut inp).unwrap();
-            c = inp.chars().next().unwrap();
-            c != 'c'
-        } {
-            match c {
-                'm' => {
-                    let mut parts = inp.splitn(3, ' ');
-                    parts.next();
-                    if let Some(ini) = parts.next() {
-                        if let Some(end) = parts.next() {
-                            let x: usize = ini.parse().unwrap_or(0);
-                            let y: usize = end.trim().parse().unwrap_or(self.mem.len() - 1);
-                            println!("{}mem {}..={} {:?}", dbg, x, y, &self.mem[x..=y]);
-                        } else {
-                            println!("{}expected m x..=y", dbg);
-                        }
+            let mut inp = String::new();
+            // end of input continues the program
+            if io::stdin().read_line(&mut inp).unwrap() == 0 {
+                return true;
+            }
+            let mut words = inp.split_whitespace();
+            let command = match words.next() {
+                Some(command) => command,
+                None => continue, // an empty line re-prompts
+            };
+            let args: Vec<usize> = words.filter_map(|w| w.parse().ok()).collect();
+            match command {
+                "c" => return true,
+                "m" | "d" if args.len() == 2 => {
+                    let last = self.last_address();
+                    let (mut x, mut y) = (args[0], args[1]);
+                    if x > y {
+                        std::mem::swap(&mut x, &mut y);
+                        println!("{}swapped range to {}..={}", dbg, x, y);
+                    }
+                    if y > last {
+                        y = last;
+                        x = x.min(last);
+                        println!("{}clamped range to {}..={}", dbg, x, y);
+                    }
+                    if command == "m" {
+                        let window = self.debug_snapshot(x..y + 1);
+                        println!("{}mem {}..={} {:?}", dbg, x, y, window.memory);
                     } else {
-                        println!("{}mem {:?}", dbg, self.mem);
+                        print!("{}", self.disassemble(x, y + 1));
                     }
                 }
-                'p' => println!("{}pointer {:?}", dbg, self.pointer),
-                'i' => println!("{}input {:?}", dbg, self.input),
-                'o' => println!("{}output {:?}", dbg, self.output),
-                'b' => println!("{}rel_base {:?}", dbg, self.rel_base),
-                _ => break,
+                "m" if args.is_empty() => println!("{}mem {:?}", dbg, snapshot.memory),
+                "m" | "d" => println!("{}expected {} x y", dbg, command),
+                "bp" | "w" if args.len() == 1 => {
+                    let (kind, set) = if command == "bp" {
+                        ("breakpoint", self.toggle_breakpoint(args[0]))
+                    } else {
+                        ("watchpoint", self.toggle_watchpoint(args[0]))
+                    };
+                    let state = if set { "set" } else { "cleared" };
+                    println!("{}{} {} at {}", dbg, kind, state, args[0]);
+                }
+                "bp" | "w" => println!("{}expected {} addr", dbg, command),
+                "p" => println!("{}pointer {:?}", dbg, snapshot.pointer),
+                "i" => println!("{}input {:?}", dbg, self.input),
+                "o" => println!("{}output {:?}", dbg, self.output),
+                "b" => println!("{}rel_base {:?}", dbg, snapshot.rel_base),
+                _ => return false,
             }
         }
     }
 
-    pub fn run_debug_mode(&mut self) {
-        let mut op: Opcode;
-        let mut old_pointer;
+    /// Same as `try_run_debug_mode`, panicking on errors
+    pub fn run_debug_mode(&mut self) -> RunState {
+        self.try_run_debug_mode()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_run_debug_mode(&mut self) -> Result<RunState, IntcodeError> {
         println!(
             "{}",
             "
             pick
-              [c]     continue
+              [s]     step
+              [c]     continue until a breakpoint or watchpoint
               [m x y] view mem in range x..=y, ignore = view all
+              [d x y] disassemble range x..=y
+              [bp a]  set or clear a breakpoint at a
+              [w a]   set or clear a watchpoint at a
               [p]     view pointer
               [i]     view input stack
               [o]     view output stack
@@ -239,24 +546,164 @@ impl<S: ProgSender, R: ProgReceiver> Program<S, R> {
             .green()
         );
 
-        while {
-            old_pointer = self.pointer;
-            op = from_num(self.mem[self.pointer]);
-            self.execute(op.clone());
-            old_pointer != self.pointer
-        } {
-            self.debug(op);
+        self.try_run_with_debugger(Self::debug)
+    }
+
+    /// Runs like `try_run_debug_mode`, handing every stop to `debugger`, which returns whether
+    /// to run freely until the next breakpoint or watch hit. Stops are checked before each
+    /// instruction, so a breakpoint on the entry address stops before it runs; going on from a
+    /// stop always runs the instruction under the pointer
+    pub fn try_run_with_debugger(
+        &mut self,
+        mut debugger: impl FnMut(&mut Self, StopReason) -> bool,
+    ) -> Result<RunState, IntcodeError> {
+        let mut free_run = false;
+        let mut reason = self.take_stop_reason().or(Some(StopReason::Step));
+        loop {
+            if let Some(reason) = reason.take() {
+                free_run = debugger(self, reason);
+            }
+            match self.try_step()? {
+                StepResult::Continue | StepResult::Output(_) => {
+                    reason = self.take_stop_reason().or(if free_run {
+                        None
+                    } else {
+                        Some(StopReason::Step)
+                    });
+                }
+                StepResult::NeedsInput => self.prompt_input(),
+                StepResult::OutputBufferFull => return Ok(RunState::OutputBufferFull),
+                StepResult::Halted => return Ok(RunState::Halted),
+            }
+        }
+    }
+
+    /// Same as `try_run`, panicking on errors. A full output returns `OutputBufferFull` with the
+    /// program paused on the output, not halted
+    #[inline]
+    pub fn run(&mut self) -> RunState {
+        self.try_run().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Runs until the program halts or pauses, in which case calling it again resumes it.
+    /// Inputs missing from the receiver are asked to stdin
+    #[inline]
+    pub fn try_run(&mut self) -> Result<RunState, IntcodeError> {
+        loop {
+            match self.try_run_until_blocked()? {
+                RunState::NeedsInput => self.prompt_input(),
+                state => return Ok(state),
+            }
+        }
+    }
+
+    /// Same as `try_run_until_blocked`, panicking on errors
+    pub fn run_until_blocked(&mut self) -> RunState {
+        self.try_run_until_blocked()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Runs until the program halts or pauses, never touching stdin: inputs missing from the
+    /// receiver pause it with `NeedsInput`, leaving the pointer on the input instruction
+    #[inline]
+    pub fn try_run_until_blocked(&mut self) -> Result<RunState, IntcodeError> {
+        loop {
+            match self.try_step()? {
+                StepResult::Continue | StepResult::Output(_) => (),
+                StepResult::NeedsInput => return Ok(RunState::NeedsInput),
+                StepResult::OutputBufferFull => return Ok(RunState::OutputBufferFull),
+                StepResult::Halted => return Ok(RunState::Halted),
+            }
         }
     }
 
-    pub fn run(&mut self) {
-        let mut op: Opcode;
-        let mut old_pointer;
-        while {
-            old_pointer = self.pointer;
-            op = from_num(self.read(self.pointer));
-            self.execute(op);
-            old_pointer != self.pointer
-        } {}
+    /// Same as `try_run_until_event`, panicking on errors
+    pub fn run_until_event(&mut self) -> StepResult {
+        self.try_run_until_event()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Runs until something other than `Continue` happens, never touching stdin
+    pub fn try_run_until_event(&mut self) -> Result<StepResult, IntcodeError> {
+        loop {
+            match self.try_step()? {
+                StepResult::Continue => (),
+                event => return Ok(event),
+            }
+        }
+    }
+
+    /// Same as `try_step`, panicking on errors
+    pub fn step(&mut self) -> StepResult {
+        self.try_step().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Runs the instruction under the pointer, unless the program has halted or has to pause.
+    /// Outputs are both put in the sender and returned
+    #[inline]
+    pub fn try_step(&mut self) -> Result<StepResult, IntcodeError> {
+        if self.halted {
+            return Ok(StepResult::Halted);
+        }
+        let op = self.fetch()?;
+        if self.output_blocks(&op) {
+            return Ok(StepResult::OutputBufferFull);
+        }
+        if let Opcode::Input(_) = op {
+            if self.pending_input.is_none() {
+                self.pending_input = self.input.get();
+            }
+            if self.pending_input.is_none() {
+                return Ok(StepResult::NeedsInput);
+            }
+        }
+        let result = match &op {
+            Opcode::Output(m0) => StepResult::Output(self.get_param(1, m0.clone())),
+            Opcode::Halt => StepResult::Halted,
+            _ => StepResult::Continue,
+        };
+        self.last_op = Some(op.clone());
+        self.instructions += 1;
+        self.watch_hit = None;
+        self.execute(op)?;
+        Ok(result)
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Decodes the instruction under the pointer, which must be in the program or in memory
+    /// written past its end
+    fn fetch(&self) -> Result<Opcode, IntcodeError> {
+        let addr = self.pointer;
+        if addr >= self.mem.len() && !self.aux_mem.contains_key(&addr) {
+            return Err(IntcodeError::PointerOutOfBounds { addr });
+        }
+        let num = self.read(addr);
+        from_num(num).ok_or(IntcodeError::Decode(DecodeError::Invalid { addr, num }))
+    }
+
+    /// Outputs wait while the output is full, leaving the pointer on them
+    fn output_blocks(&self, op: &Opcode) -> bool {
+        matches!(op, Opcode::Output(_)) && self.output.is_full()
+    }
+}
+
+impl Program<NoIo, NoIo> {
+    /// A program without any I/O plumbing, meant as the benchmark target for the interpreter
+    /// loop: time `run_until_blocked` and divide `instructions` by it to get the instruction
+    /// throughput. `run` would ask stdin for any input, so it is not a fit
+    pub fn headless(data: &[Int]) -> Self {
+        Program::new(data, NoIo, NoIo)
+    }
+}
+
+fn toggle(points: &mut HashSet<usize>, addr: usize) -> bool {
+    if points.remove(&addr) {
+        false
+    } else {
+        points.insert(addr);
+        true
     }
 }
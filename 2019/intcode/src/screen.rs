@@ -0,0 +1,87 @@
+use crate::program::Int;
+use grid::{render_grid_within, GridStyle};
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// Sparse grid of values drawn by a program, x growing to the right and y growing downwards
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Screen {
+    cells: HashMap<(Int, Int), Int>,
+    bounds: Option<((Int, Int), (Int, Int))>, // (min, max) corners of every cell ever set
+}
+
+impl Screen {
+    pub fn new() -> Self {
+        Screen::default()
+    }
+
+    pub fn set(&mut self, x: Int, y: Int, value: Int) {
+        self.cells.insert((x, y), value);
+        self.bounds = Some(match self.bounds {
+            None => ((x, y), (x, y)),
+            Some(((x_min, y_min), (x_max, y_max))) => {
+                ((x_min.min(x), y_min.min(y)), (x_max.max(x), y_max.max(y)))
+            }
+        });
+    }
+
+    pub fn get(&self, x: Int, y: Int) -> Option<Int> {
+        self.cells.get(&(x, y)).cloned()
+    }
+
+    /// Number of cells set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The `(min, max)` corners, `None` while nothing has been set
+    pub fn bounds(&self) -> Option<((Int, Int), (Int, Int))> {
+        self.bounds
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = Int> + '_ {
+        self.cells.values().cloned()
+    }
+
+    /// The cells set and their values, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = ((Int, Int), Int)> + '_ {
+        self.cells.iter().map(|(&pos, &value)| (pos, value))
+    }
+
+    /// Draws the tracked bounding box row by row with the grid renderer shared with the 2020
+    /// painter, each cell as the char `map` gives for its value and cells never set as a 0
+    pub fn render(&self, map: impl Fn(Int) -> char) -> String {
+        self.render_cells(|value| map(value).to_string())
+    }
+
+    /// Same as `render` with a string per cell, so the arcade can keep its two char glyphs
+    pub fn render_str<'a>(&self, map: impl Fn(Int) -> &'a str) -> String {
+        self.render_cells(map)
+    }
+
+    fn render_cells<S: AsRef<str>>(&self, map: impl Fn(Int) -> S) -> String {
+        match self.bounds {
+            Some(bounds) => render_grid_within(
+                &self.cells,
+                bounds,
+                |value| map(value.cloned().unwrap_or_default()),
+                &GridStyle::default(),
+            ),
+            None => String::new(),
+        }
+    }
+}
+
+impl FromIterator<((Int, Int), Int)> for Screen {
+    fn from_iter<I: IntoIterator<Item = ((Int, Int), Int)>>(iter: I) -> Self {
+        let mut screen = Screen::new();
+        for ((x, y), value) in iter {
+            screen.set(x, y, value);
+        }
+        screen
+    }
+}
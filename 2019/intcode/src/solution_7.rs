@@ -0,0 +1,11 @@
+use crate::program::Int;
+use crate::{solution_7a, solution_7b, Part};
+
+/// Highest thruster signal, with the amplifiers in series for part one and in a feedback loop
+/// for part two. The parts are independent
+pub fn solve(data: &[Int], part: Part) -> Int {
+    match part {
+        Part::One => solution_7a::run_solution(data, false).0,
+        Part::Two => solution_7b::run_solution(data.to_vec(), false),
+    }
+}
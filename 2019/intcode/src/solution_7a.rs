@@ -1,7 +1,7 @@
 use crate::program::{Int, Program};
 use itertools::Itertools;
 
-pub fn run_solution(data: &Vec<Int>, debug: bool) -> (Int, Vec<Int>) {
+pub fn run_solution(data: &[Int], debug: bool) -> (Int, Vec<Int>) {
     let perms = (0..5).permutations(5);
     let mut input = vec![];
     let mut output = vec![0];
@@ -12,7 +12,7 @@ pub fn run_solution(data: &Vec<Int>, debug: bool) -> (Int, Vec<Int>) {
             let last_out = output.pop().expect("last amplifier had no output");
             input.push(last_out);
             input.push(*phase);
-            let mut program = Program::new(&data, &mut input, &mut output);
+            let mut program = Program::new(data, &mut input, &mut output);
             if debug {
                 program.run_debug_mode();
             } else {
@@ -0,0 +1,89 @@
+use crate::program::Int;
+use Direction::*;
+
+/// Facing of a grid walker, on screen coordinates: `Up` decreases y
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting upwards
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    pub fn left(self) -> Direction {
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+
+    pub fn right(self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.left().left()
+    }
+
+    /// The `(dx, dy)` of a step in this direction
+    pub fn delta(self) -> (Int, Int) {
+        match self {
+            Up => (0, -1),
+            Right => (1, 0),
+            Down => (0, 1),
+            Left => (-1, 0),
+        }
+    }
+
+    /// The neighbour of `(x, y)` in this direction
+    pub fn step(self, (x, y): (Int, Int)) -> (Int, Int) {
+        let (dx, dy) = self.delta();
+        (x + dx, y + dy)
+    }
+}
+
+/// Something walking the grid with a position and a facing
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Turtle {
+    position: (Int, Int),
+    facing: Direction,
+}
+
+impl Turtle {
+    pub fn new(position: (Int, Int), facing: Direction) -> Self {
+        Turtle { position, facing }
+    }
+
+    pub fn position(&self) -> (Int, Int) {
+        self.position
+    }
+
+    pub fn facing(&self) -> Direction {
+        self.facing
+    }
+
+    pub fn turn_left(&mut self) {
+        self.facing = self.facing.left();
+    }
+
+    pub fn turn_right(&mut self) {
+        self.facing = self.facing.right();
+    }
+
+    /// Moves one cell towards the facing and returns the new position
+    pub fn forward(&mut self) -> (Int, Int) {
+        self.position = self.facing.step(self.position);
+        self.position
+    }
+}
@@ -1,8 +1,18 @@
 extern crate intcode;
-use intcode::program::{Int, Program};
+use intcode::disasm::disassemble;
+use intcode::error::IntcodeError;
+use intcode::io::{
+    channel, try_channel, AsciiReceiver, AsciiSender, ConstInput, NoIo, QueueIo, VecOutput,
+};
+use intcode::opcode::{decode_at, DecodeError, Mode, Opcode};
+use intcode::program::{Int, ProgReceiver, ProgSender, Program, RunState, StepResult, StopReason};
+use intcode::screen::Screen;
+use intcode::turtle::{Direction, Turtle};
 use intcode::{get_data_from_path, get_data_from_str};
+use std::collections::VecDeque;
+use std::thread;
 
-use intcode::{solution_7a, solution_7b};
+use intcode::{solution_7, solution_7a, solution_7b, Part};
 
 #[test]
 fn test_02() {
@@ -206,3 +216,623 @@ fn test_09a_final() {
     Program::new(&data, &mut input, &mut output).run();
     assert_eq!(&output, &[4080871669]);
 }
+
+#[test]
+fn test_debug_snapshot() {
+    // set the rel base to 5, write 7 past the end of the program and halt
+    let data = get_data_from_str("109,5,21101,3,4,20,99");
+    let mut input = vec![];
+    let mut output = vec![];
+    let mut prog = Program::new(&data, &mut input, &mut output);
+    prog.run();
+
+    let snapshot = prog.debug_snapshot(4..8);
+    assert_eq!(snapshot.pointer, 6);
+    assert_eq!(snapshot.rel_base, 5);
+    assert_eq!(snapshot.window, 4..8);
+    assert_eq!(snapshot.memory, vec![4, 20, 99, 0]);
+    assert!(matches!(snapshot.last_op, Some(Opcode::Halt)));
+    assert_eq!(prog.debug_snapshot(25..26).memory, vec![7]);
+}
+
+#[test]
+fn test_last_address() {
+    let data = get_data_from_str("1101,1,2,3,99");
+    let mut input = vec![];
+    let mut output = vec![];
+    let mut prog = Program::new(&data, &mut input, &mut output);
+    prog.run();
+    assert_eq!(prog.last_address(), 4);
+
+    let data = get_data_from_str("109,5,21101,3,4,20,99");
+    let mut input = vec![];
+    let mut output = vec![];
+    let mut prog = Program::new(&data, &mut input, &mut output);
+    prog.run();
+    assert_eq!(prog.last_address(), 25);
+}
+
+#[test]
+fn test_screen() {
+    let mut screen = Screen::new();
+    assert!(screen.is_empty());
+    assert_eq!(screen.bounds(), None);
+    assert_eq!(screen.render(|_| '#'), "");
+
+    screen.set(-1, 2, 1);
+    screen.set(1, 3, 2);
+    screen.set(1, 3, 1);
+    assert_eq!(screen.len(), 2);
+    assert_eq!(screen.get(1, 3), Some(1));
+    assert_eq!(screen.get(0, 3), None);
+    assert_eq!(screen.bounds(), Some(((-1, 2), (1, 3))));
+    let mut cells: Vec<_> = screen.iter().collect();
+    cells.sort();
+    assert_eq!(cells, vec![((-1, 2), 1), ((1, 3), 1)]);
+
+    assert_eq!(
+        screen.render(|v| if v == 1 { '#' } else { '.' }),
+        "#..\n..#\n"
+    );
+    assert_eq!(
+        screen.render_str(|v| if v == 1 { "()" } else { ".." }),
+        "()....\n....()\n"
+    );
+    let collected: Screen = vec![((-1, 2), 1), ((1, 3), 1)].into_iter().collect();
+    assert_eq!(collected, screen);
+}
+
+#[test]
+fn test_turtle() {
+    let mut turtle = Turtle::new((0, 0), Direction::Up);
+    assert_eq!(turtle.forward(), (0, -1));
+    turtle.turn_right();
+    assert_eq!(turtle.facing(), Direction::Right);
+    assert_eq!(turtle.forward(), (1, -1));
+    turtle.turn_right();
+    turtle.turn_right();
+    turtle.turn_left();
+    assert_eq!(turtle.facing(), Direction::Down);
+    assert_eq!(turtle.forward(), (1, 0));
+    assert_eq!(turtle.position(), (1, 0));
+
+    for &dir in &Direction::ALL {
+        assert_eq!(dir.left().right(), dir);
+        assert_eq!(dir.right().right(), dir.opposite());
+        assert_eq!(dir.opposite().step(dir.step((3, 4))), (3, 4));
+    }
+}
+
+#[test]
+fn test_headless() {
+    // counts [100] down from 3, printing it every time
+    let data = get_data_from_str("1101,0,3,100,1001,100,-1,100,4,100,1005,100,4,99");
+    let mut prog = Program::headless(&data);
+    assert_eq!(prog.instructions(), 0);
+    assert_eq!(prog.run_until_blocked(), RunState::Halted);
+    assert_eq!(prog.instructions(), 11);
+    assert_eq!(prog.debug_snapshot(100..101).memory, vec![0]);
+    // there is no input to give, so the program pauses on it
+    let mut prog = Program::headless(&[3, 0, 99]);
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(prog.run_until_event(), StepResult::NeedsInput);
+    assert_eq!(prog.debug_snapshot(0..0).pointer, 0);
+    // the same counter, asking for input once it is done, returns without reading stdin
+    let data = get_data_from_str("1101,0,3,100,1001,100,-1,100,4,100,1005,100,4,3,100,99");
+    let mut prog = Program::headless(&data);
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(prog.instructions(), 10);
+    assert_eq!(prog.debug_snapshot(0..0).pointer, 13);
+}
+
+#[test]
+fn test_strict() {
+    // turns the 0 after the first instruction into a halt
+    let data = get_data_from_str("1101,98,1,4,0");
+    let mut prog = Program::headless(&data);
+    assert_eq!(prog.try_run(), Ok(RunState::Halted));
+    assert_eq!(prog.peak_mem(), &[1101, 98, 1, 4, 99]);
+
+    let mut prog = Program::headless(&data);
+    prog.set_strict(true);
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::SelfModifyingCode { addr: 4 })
+    );
+    assert_eq!(prog.peak_mem(), &data[..]);
+
+    // writing to the running instruction or past the next one is fine
+    let data = get_data_from_str("1101,1,1,0,1101,1,1,12,99,0,0,0");
+    let mut prog = Program::headless(&data);
+    prog.set_strict(true);
+    assert_eq!(prog.try_run(), Ok(RunState::Halted));
+    assert_eq!(prog.peak_mem()[..1], [2]);
+}
+
+#[test]
+fn test_output_backpressure() {
+    let data = get_data_from_str("104,1,104,2,104,3,104,4,104,5,99");
+    let mut prog = Program::new(&data, NoIo, VecOutput::with_max(2));
+    let mut drained = vec![];
+    while let Ok(RunState::OutputBufferFull) = prog.try_run() {
+        assert_eq!(prog.peak_output().values().len(), 2);
+        drained.extend(prog.output_mut().drain());
+    }
+    drained.extend(prog.output_mut().drain());
+    assert_eq!(drained, vec![1, 2, 3, 4, 5]);
+    assert_eq!(prog.instructions(), 6);
+
+    let mut prog = Program::new(&data, NoIo, VecOutput::new());
+    assert_eq!(prog.try_run(), Ok(RunState::Halted));
+    assert_eq!(prog.peak_output().values(), &[1, 2, 3, 4, 5]);
+
+    // run tells a full output apart from halting
+    let mut prog = Program::new(&data, NoIo, VecOutput::with_max(2));
+    assert_eq!(prog.run(), RunState::OutputBufferFull);
+    assert!(!prog.is_halted());
+    prog.output_mut().drain();
+    assert_eq!(prog.run(), RunState::OutputBufferFull);
+    prog.output_mut().drain();
+    assert_eq!(prog.run(), RunState::Halted);
+}
+
+#[test]
+fn test_decode_at() {
+    let mem = get_data_from_str("1002,4,3,4,99,21107,3,42,30001");
+    assert!(matches!(
+        decode_at(&mem, 6),
+        Ok((Opcode::Input(Mode::Position), 2))
+    ));
+    assert!(matches!(
+        decode_at(&mem, 0),
+        Ok((
+            Opcode::Multiply(Mode::Position, Mode::Inmediate, Mode::Position),
+            4
+        ))
+    ));
+    assert!(matches!(decode_at(&mem, 4), Ok((Opcode::Halt, 1))));
+    assert!(matches!(
+        decode_at(&mem, 5),
+        Ok((
+            Opcode::LessThan(Mode::Inmediate, Mode::Inmediate, Mode::Relative),
+            4
+        ))
+    ));
+    assert_eq!(
+        decode_at(&mem, 7).unwrap_err(),
+        DecodeError::Invalid { addr: 7, num: 42 }
+    );
+    assert_eq!(
+        decode_at(&mem, 8).unwrap_err(),
+        DecodeError::Invalid {
+            addr: 8,
+            num: 30001
+        }
+    );
+    assert_eq!(
+        decode_at(&mem, 9).unwrap_err(),
+        DecodeError::OutOfBounds { addr: 9 }
+    );
+
+    let mut prog = Program::headless(&get_data_from_str("1101,40,2,4,0"));
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::Decode(DecodeError::Invalid {
+            addr: 4,
+            num: 42
+        }))
+    );
+}
+
+#[test]
+fn test_max_address() {
+    // writes to 1000, then to a negative address, which wraps around to a huge one
+    let data = get_data_from_str("1101,1,1,1000,1101,1,1,-1,99");
+    let mut prog = Program::headless(&data);
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::AddressTooLarge { addr: usize::MAX })
+    );
+    assert_eq!(prog.last_address(), 1000);
+
+    let mut prog = Program::headless(&data);
+    prog.set_max_address(999);
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::AddressTooLarge { addr: 1000 })
+    );
+    assert_eq!(prog.last_address(), 8);
+    // an input that can not be stored is kept for the retry
+    let data = get_data_from_str("3,1000,99");
+    let mut prog = Program::new(&data, VecDeque::from(vec![7]), NoIo);
+    prog.set_max_address(999);
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::AddressTooLarge { addr: 1000 })
+    );
+    assert!(prog.peak_input().is_empty());
+    prog.set_max_address(1000);
+    assert_eq!(prog.run_until_blocked(), RunState::Halted);
+    assert_eq!(prog.debug_snapshot(1000..1001).memory, vec![7]);
+    assert_eq!(prog.inputs_consumed(), 1);
+}
+
+#[test]
+fn test_inputs_consumed() {
+    // echoes two inputs
+    let data = get_data_from_str("3,0,4,0,3,0,4,0,99");
+    let mut input = vec![8, 7];
+    let mut output = vec![];
+    let mut prog = Program::new(&data, &mut input, &mut output);
+    assert_eq!(prog.inputs_consumed(), 0);
+    prog.run();
+    assert_eq!(prog.inputs_consumed(), 2);
+    assert_eq!(prog.instructions(), 5);
+}
+
+#[test]
+fn test_from_vec() {
+    let data = get_data_from_str("1002,4,3,4,33");
+    let mut input = vec![];
+    let mut output = vec![];
+    let mut prog = Program::from_vec(data, &mut input, &mut output);
+    prog.run();
+    assert_eq!(prog.peak_mem(), &[1002, 4, 3, 4, 99]);
+}
+
+#[test]
+fn test_pointer_out_of_bounds() {
+    // jumps past the end, first to a halt written there and then to nowhere
+    let data = get_data_from_str("1101,0,99,50,1105,1,50");
+    let mut prog = Program::headless(&data);
+    assert_eq!(prog.try_run(), Ok(RunState::Halted));
+
+    let data = get_data_from_str("1101,0,99,50,1105,1,51");
+    let mut prog = Program::headless(&data);
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::PointerOutOfBounds { addr: 51 })
+    );
+
+    let mut prog = Program::headless(&get_data_from_str("1101,0,0,0"));
+    assert_eq!(
+        prog.try_run(),
+        Err(IntcodeError::PointerOutOfBounds { addr: 4 })
+    );
+}
+
+#[test]
+fn test_07_parts() {
+    let data = get_data_from_path("data/day07_final.txt");
+    assert_eq!(solution_7::solve(&data, Part::One), 92663);
+    assert_eq!(solution_7::solve(&data, Part::Two), 14365052);
+    assert_eq!("2".parse(), Ok(Part::Two));
+    assert!("3".parse::<Part>().is_err());
+}
+
+#[test]
+fn test_part_from_args() {
+    let args = |args: &[&str]| Part::from_args(args.iter().map(|a| a.to_string()));
+    assert_eq!(args(&[]), Ok(vec![Part::One, Part::Two]));
+    assert_eq!(args(&["--part", "two"]), Ok(vec![Part::Two]));
+    assert!(args(&["--part", "3"]).is_err());
+    assert!(args(&["--part"]).is_err());
+    assert!(args(&["--part", "1", "2"]).is_err());
+    assert!(args(&["1"]).is_err());
+}
+
+#[test]
+fn test_run_until_blocked() {
+    // both read a number, output it plus one or doubled respectively, and start over
+    let inc = get_data_from_str("3,20,1001,20,1,20,4,20,1105,1,0");
+    let double = get_data_from_str("3,20,1002,20,2,20,4,20,1105,1,0");
+    let mut a = Program::new(&inc, VecDeque::from(vec![0]), VecOutput::new());
+    let mut b = Program::new(&double, VecDeque::new(), VecOutput::new());
+
+    let mut sent: Vec<Int> = vec![];
+    for _ in 0..3 {
+        assert_eq!(a.run_until_blocked(), RunState::NeedsInput);
+        assert_eq!(a.debug_snapshot(0..0).pointer, 0);
+        let out = a.output_mut().drain();
+        sent.extend(&out);
+        b.input_mut().extend(out);
+
+        assert_eq!(b.run_until_blocked(), RunState::NeedsInput);
+        let out = b.output_mut().drain();
+        sent.extend(&out);
+        a.input_mut().extend(out);
+    }
+    assert_eq!(sent, vec![1, 2, 3, 6, 7, 14]);
+    assert_eq!(a.inputs_consumed(), 3);
+    assert_eq!(b.inputs_consumed(), 3);
+
+    // a real halt is told apart from waiting for input
+    let mut prog = Program::new(&get_data_from_str("99"), VecDeque::new(), NoIo);
+    assert_eq!(prog.run_until_blocked(), RunState::Halted);
+
+    let mut prog = Program::headless(&get_data_from_str("98"));
+    assert!(prog.try_run_until_blocked().is_err());
+}
+
+#[test]
+fn test_step_self_jump() {
+    let mut prog = Program::headless(&get_data_from_str("1105,1,0"));
+    for _ in 0..10 {
+        assert_eq!(prog.step(), StepResult::Continue);
+    }
+    assert!(!prog.is_halted());
+    assert_eq!(prog.debug_snapshot(0..0).pointer, 0);
+}
+
+#[test]
+fn test_step_resume_after_input() {
+    let data = get_data_from_str("3,0,4,0,99");
+    let mut prog = Program::new(&data, VecDeque::new(), VecOutput::new());
+    assert_eq!(prog.run_until_event(), StepResult::NeedsInput);
+    assert_eq!(prog.run_until_event(), StepResult::NeedsInput);
+    prog.input_mut().push_back(42);
+    assert_eq!(prog.run_until_event(), StepResult::Output(42));
+    assert_eq!(prog.run_until_event(), StepResult::Halted);
+    assert!(prog.is_halted());
+
+    // running a halted program does nothing
+    let instructions = prog.instructions();
+    assert_eq!(prog.run_until_event(), StepResult::Halted);
+    prog.run();
+    assert_eq!(prog.instructions(), instructions);
+    assert_eq!(prog.peak_output().values(), &[42]);
+}
+
+#[test]
+fn test_run_until_event_quine() {
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    let mut prog = Program::new(&get_data_from_str(quine), VecDeque::new(), NoIo);
+    let mut output = vec![];
+    while let StepResult::Output(out) = prog.run_until_event() {
+        output.push(out);
+    }
+    assert!(prog.is_halted());
+    assert_eq!(output, get_data_from_str(quine));
+}
+
+#[test]
+fn test_io_const_input() {
+    let data = get_data_from_path("data/day05_final.txt");
+    let mut prog = Program::new(&data, ConstInput::new(&[5]), VecOutput::new());
+    prog.run();
+    assert_eq!(prog.peak_output().values(), &[15724522]);
+
+    let mut input = ConstInput::new(&[1, 2]);
+    assert_eq!(input.get(), Some(1));
+    assert_eq!(input.get(), Some(2));
+    assert_eq!(input.get(), None);
+}
+
+#[test]
+fn test_io_channels() {
+    let (to_a, a_in) = channel();
+    let (a_out, b_in) = channel();
+    let (b_out, from_b) = channel();
+    let double = get_data_from_str("3,20,1002,20,2,20,4,20,99");
+    let inc = get_data_from_str("3,20,1001,20,1,20,4,20,99");
+    let a = thread::spawn(move || Program::new(&double, a_in, a_out).run());
+    let b = thread::spawn(move || Program::new(&inc, b_in, b_out).run());
+    to_a.0.send(5).unwrap();
+    assert_eq!(from_b.0.recv(), Ok(11));
+    a.join().unwrap();
+    b.join().unwrap();
+}
+
+#[test]
+fn test_io_try_channel() {
+    // both programs take turns on this thread, pausing while their input is empty
+    let (to_a, a_in) = try_channel();
+    let (a_out, b_in) = try_channel();
+    let (b_out, from_b) = try_channel();
+    let mut a = Program::new(&get_data_from_str("3,20,1002,20,2,20,4,20,99"), a_in, a_out);
+    let mut b = Program::new(&get_data_from_str("3,20,1001,20,1,20,4,20,99"), b_in, b_out);
+    assert_eq!(a.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(b.run_until_blocked(), RunState::NeedsInput);
+    to_a.0.send(5).unwrap();
+    assert_eq!(b.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(a.run_until_blocked(), RunState::Halted);
+    assert_eq!(b.run_until_blocked(), RunState::Halted);
+    assert_eq!(from_b.0.try_recv(), Ok(11));
+}
+
+#[test]
+fn test_io_queue_feedback() {
+    // adds one to its own output until it reaches 5
+    let data = get_data_from_str("3,20,1001,20,1,20,4,20,1008,20,5,21,1006,21,0,99");
+    let queue = QueueIo::new();
+    queue.push(0);
+    let mut prog = Program::new(&data, queue.clone(), queue.clone());
+    assert_eq!(prog.run_until_blocked(), RunState::Halted);
+    assert_eq!(queue.values(), vec![5]);
+}
+
+#[test]
+fn test_io_ascii_echo() {
+    // echoes its input up to the newline and then prints a big number
+    let data = get_data_from_str("3,100,4,100,1008,100,10,101,1006,101,0,104,1000000,99");
+    let mut input = AsciiReceiver::new();
+    input.push_line("NOT A J");
+    assert_eq!(input.len(), 8);
+    let mut prog = Program::new(&data, input, AsciiSender::new());
+    prog.run();
+    assert!(prog.peak_input().is_empty());
+    assert_eq!(prog.peak_output().log(), "NOT A J\n");
+    assert_eq!(prog.peak_output().pending(), "");
+    assert_eq!(prog.peak_output().last_big_value(), Some(1000000));
+
+    let mut output = AsciiSender::new();
+    for c in "ab\ncd".bytes() {
+        output.put(Int::from(c));
+    }
+    assert_eq!(output.take_log(), "ab\n");
+    assert_eq!(output.log(), "");
+    assert_eq!(output.pending(), "cd");
+}
+
+#[test]
+fn test_snapshot_fork() {
+    // reads a number, stores it doubled past the end of the program, outputs it and starts over
+    let data = get_data_from_str("3,100,1002,100,2,100,4,100,1105,1,0");
+    let mut prog = Program::new(&data, VecDeque::from(vec![1]), VecOutput::new());
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    let state = prog.snapshot();
+
+    let mut fork = prog.fork(VecDeque::from(vec![10]), VecOutput::new());
+    prog.input_mut().push_back(3);
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(fork.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(prog.peak_output().values(), &[2, 6]);
+    assert_eq!(fork.peak_output().values(), &[20]);
+    assert_eq!(prog.debug_snapshot(100..101).memory, vec![6]);
+    assert_eq!(fork.debug_snapshot(100..101).memory, vec![20]);
+    assert_eq!(fork.inputs_consumed(), 2);
+
+    // back to waiting for the second input, with the first one still in memory
+    prog.restore(&state);
+    assert_eq!(prog.debug_snapshot(100..101).memory, vec![2]);
+    prog.input_mut().push_back(4);
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(prog.peak_output().values(), &[2, 6, 8]);
+
+    let mut clone = prog.clone();
+    clone.input_mut().push_back(5);
+    assert_eq!(clone.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(clone.peak_output().values(), &[2, 6, 8, 10]);
+    assert_eq!(prog.peak_output().values(), &[2, 6, 8]);
+
+    prog.reset(&data);
+    assert_eq!(prog.inputs_consumed(), 0);
+    assert_eq!(prog.last_address(), data.len() - 1);
+    prog.input_mut().push_back(7);
+    assert_eq!(prog.run_until_blocked(), RunState::NeedsInput);
+    assert_eq!(prog.peak_output().values(), &[2, 6, 8, 14]);
+    // a watch hit from before going back is not reported
+    let data = get_data_from_str("1101,1,1,5,99,0");
+    let mut prog = Program::headless(&data);
+    prog.toggle_watchpoint(5);
+    let state = prog.snapshot();
+    prog.step();
+    prog.restore(&state);
+    assert_eq!(prog.take_stop_reason(), None);
+    prog.step();
+    prog.reset(&data);
+    assert_eq!(prog.take_stop_reason(), None);
+}
+
+#[test]
+fn test_disassemble() {
+    let mem = get_data_from_str("1002,4,3,4,42,21107,-3,7,-1,109,19,204,-34,1105");
+    assert_eq!(
+        disassemble(&mem, 0, mem.len()),
+        "    0: MUL [4], 3, [4]
+    4: DATA 42
+    5: LT -3, 7, [rb-1]
+    9: ARB 19
+   11: OUT [rb-34]
+   13: DATA 1105
+"
+    );
+    assert_eq!(
+        disassemble(&mem, 5, 10),
+        "    5: LT -3, 7, [rb-1]\n    9: ARB 19\n"
+    );
+    assert_eq!(disassemble(&mem, 20, 30), "");
+
+    // the program stops at its last address, even if asked for more
+    let prog = Program::headless(&get_data_from_str("3,9,99"));
+    assert_eq!(prog.disassemble(0, 5), "    0: IN [9]\n    2: HALT\n");
+    assert_eq!(prog.disassemble(0, usize::MAX), prog.disassemble(0, 3));
+    assert_eq!(prog.disassemble(2, usize::MAX), "    2: HALT\n");
+    assert_eq!(prog.disassemble(7, 3), "");
+
+    // the parameters of the last instruction are read past the end of the program
+    let prog = Program::headless(&get_data_from_str("99,1101"));
+    assert_eq!(prog.disassemble(1, 2), "    1: ADD 0, 0, [0]\n");
+    assert_eq!(disassemble(&[99, 1101], 1, 2), "    1: DATA 1101\n");
+}
+
+#[test]
+fn test_breakpoints() {
+    let mut prog = Program::headless(&get_data_from_str("99"));
+    assert!(prog.toggle_breakpoint(3));
+    assert!(!prog.toggle_breakpoint(3));
+    assert!(prog.toggle_watchpoint(3));
+    assert!(prog.toggle_breakpoint(3));
+    // adds 2 to [7] and halts, with a breakpoint on the halt and a watch on [7]
+    let mut prog = Program::headless(&get_data_from_str("1001,7,2,7,99,0,0,0"));
+    prog.toggle_breakpoint(4);
+    prog.toggle_watchpoint(7);
+    assert_eq!(prog.take_stop_reason(), None);
+    assert_eq!(prog.step(), StepResult::Continue);
+    assert_eq!(
+        prog.take_stop_reason(),
+        Some(StopReason::Watch {
+            addr: 7,
+            old: 0,
+            new: 2
+        })
+    );
+    assert_eq!(prog.take_stop_reason(), Some(StopReason::Breakpoint(4)));
+    assert_eq!(prog.take_stop_reason(), Some(StopReason::Breakpoint(4)));
+    assert_eq!(prog.step(), StepResult::Halted);
+    assert_eq!(prog.debug_snapshot(7..8).memory, vec![2]);
+}
+
+#[test]
+fn test_debugger_stops() {
+    // a breakpoint on the entry address stops before the first instruction runs, and running
+    // freely from it stops again on the halt
+    let mut prog = Program::headless(&get_data_from_str("1001,7,2,7,99,0,0,0"));
+    prog.toggle_breakpoint(0);
+    prog.toggle_breakpoint(4);
+    let mut stops = vec![];
+    let state = prog.try_run_with_debugger(|prog, reason| {
+        stops.push((reason, prog.instructions()));
+        true
+    });
+    assert_eq!(state, Ok(RunState::Halted));
+    assert_eq!(
+        stops,
+        vec![
+            (StopReason::Breakpoint(0), 0),
+            (StopReason::Breakpoint(4), 1)
+        ]
+    );
+
+    // a watch hit landing on a breakpoint shares its stop
+    let mut prog = Program::headless(&get_data_from_str("1001,7,2,7,99,0,0,0"));
+    prog.toggle_breakpoint(4);
+    prog.toggle_watchpoint(7);
+    let mut stops = vec![];
+    let state = prog.try_run_with_debugger(|_, reason| {
+        stops.push(reason);
+        true
+    });
+    assert_eq!(state, Ok(RunState::Halted));
+    assert_eq!(
+        stops,
+        vec![
+            StopReason::Step,
+            StopReason::Watch {
+                addr: 7,
+                old: 0,
+                new: 2
+            }
+        ]
+    );
+
+    // without breakpoints, stepping stops before every instruction
+    let mut prog = Program::headless(&get_data_from_str("1001,7,2,7,99,0,0,0"));
+    let mut stops = vec![];
+    let state = prog.try_run_with_debugger(|prog, reason| {
+        stops.push((reason, prog.instructions()));
+        false
+    });
+    assert_eq!(state, Ok(RunState::Halted));
+    assert_eq!(stops, vec![(StopReason::Step, 0), (StopReason::Step, 1)]);
+}
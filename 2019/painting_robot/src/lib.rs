@@ -1,44 +1,18 @@
 use intcode::get_data_from_path;
 use intcode::program::{Int, Program};
+use intcode::turtle::{Direction, Turtle};
 use std::collections::HashMap;
 use std::sync::mpsc::channel;
 use std::thread;
-use Direction::*;
-
-enum Direction {
-    Up,
-    Left,
-    Right,
-    Down,
-}
-
-impl Direction {
-    fn left(&mut self) {
-        *self = match self {
-            Up => Left,
-            Left => Down,
-            Down => Right,
-            Right => Up,
-        }
-    }
-
-    fn right(&mut self) {
-        *self = match self {
-            Up => Right,
-            Right => Down,
-            Down => Left,
-            Left => Up,
-        }
-    }
-}
 
+/// Runs the robot starting on a cell of color `start` and returns the color of every cell it
+/// painted, keyed by `(x, y)` with y growing upwards
 pub fn doshit(start: Int) -> HashMap<(Int, Int), Int> {
     let (input_sender, input_receiver) = channel();
     let (output_sender, output_receiver) = channel();
     let mut painted_cells = HashMap::new();
     let data = get_data_from_path("data/final.txt");
-    let (mut x, mut y) = (0, 0);
-    let mut direction = Direction::Up;
+    let mut robot = Turtle::new((0, 0), Direction::Up);
     let mut i = 0;
 
     let prog_thread = thread::spawn(move || {
@@ -50,23 +24,18 @@ pub fn doshit(start: Int) -> HashMap<(Int, Int), Int> {
     while let Ok(out) = output_receiver.recv() {
         if i % 2 == 0 {
             // first output: paint
-            painted_cells.insert((x, y), out);
+            painted_cells.insert(upwards(robot.position()), out);
             i += 1;
             continue;
         }
         if out == 0 {
-            direction.left(); // second output and it is a 0 (turn left)
+            robot.turn_left(); // second output and it is a 0 (turn left)
         } else {
-            direction.right(); // second output and it is a 1 (turn right)
-        }
-        match direction {
-            Up => y += 1,
-            Left => x -= 1,
-            Down => y -= 1,
-            Right => x += 1,
+            robot.turn_right(); // second output and it is a 1 (turn right)
         }
+        let position = upwards(robot.forward());
         if input_sender
-            .send(painted_cells.get(&(x, y)).cloned().unwrap_or_default())
+            .send(painted_cells.get(&position).cloned().unwrap_or_default())
             .is_err()
         {
             println!("program halted");
@@ -77,3 +46,9 @@ pub fn doshit(start: Int) -> HashMap<(Int, Int), Int> {
     prog_thread.join().unwrap();
     painted_cells
 }
+
+/// The turtle walks screen coordinates, where `Up` decreases y, so y is mirrored to keep it
+/// growing upwards
+fn upwards((x, y): (Int, Int)) -> (Int, Int) {
+    (x, -y)
+}
@@ -0,0 +1,341 @@
+use intcode::program::{Int, Program};
+use intcode::screen::Screen;
+use intcode::turtle::Direction::{self, *};
+use intcode::Part;
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use termion::{clear, color, cursor};
+use Tile::*;
+
+pub const WALL: Int = 0;
+pub const MOVED: Int = 1;
+pub const OXYGEN: Int = 2;
+
+/// Movement command understood by the droid
+pub fn command(dir: Direction) -> Int {
+    match dir {
+        Up => 1,
+        Down => 2,
+        Left => 3,
+        Right => 4,
+    }
+}
+
+/// What the droid found, relative to where it started: the status it answered for every cell
+/// it tried to move to, `WALL`, `MOVED` or `OXYGEN`, and where the oxygen system is
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+    pub tiles: Screen,
+    pub oxygen: Option<(Int, Int)>,
+}
+
+impl Map {
+    pub fn is_open(&self, (x, y): (Int, Int)) -> bool {
+        self.tiles.get(x, y).is_some_and(|tile| tile != WALL)
+    }
+
+    /// Steps needed to reach each open cell from `from`
+    pub fn distances(&self, from: (Int, Int)) -> HashMap<(Int, Int), usize> {
+        let mut distances = HashMap::new();
+        distances.insert(from, 0);
+        let mut pending = VecDeque::new();
+        pending.push_back(from);
+        while let Some(pos) = pending.pop_front() {
+            let distance = distances[&pos] + 1;
+            for dir in &Direction::ALL {
+                let next = dir.step(pos);
+                if self.is_open(next) && !distances.contains_key(&next) {
+                    distances.insert(next, distance);
+                    pending.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+}
+
+const INFINITY: Int = Int::MAX;
+const SOURCE: (Int, Int) = (35, 25);
+const TARGET_NOT_FOUND: (Int, Int) = (INFINITY, INFINITY);
+
+/// Walks the droid breadth first from `SOURCE`, drawing what it finds on `stdout` with the
+/// terminal cursor. Once it finds the oxygen system it starts over from there
+pub struct Explorer<W: Write> {
+    robot: (Int, Int),
+    target: (Int, Int),
+    stdout: W,
+    visited: Screen,
+    pending: VecDeque<(Int, Int)>,
+    distances: HashMap<(Int, Int), Int>,
+    predecesors: HashMap<(Int, Int), Direction>,
+    robot_out: Receiver<Int>,
+    robot_in: Sender<Int>,
+    no_target: bool,
+    source: (Int, Int),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    OpenSpace,
+    Wall,
+    Target,
+    Robot,
+}
+
+impl<W: Write> Explorer<W> {
+    pub fn new(stdout: W, robot_in: Sender<Int>, robot_out: Receiver<Int>) -> Explorer<W> {
+        Explorer {
+            robot: SOURCE,
+            target: TARGET_NOT_FOUND,
+            stdout,
+            visited: Screen::new(),
+            pending: VecDeque::new(),
+            distances: HashMap::new(),
+            predecesors: HashMap::new(),
+            robot_in,
+            robot_out,
+            no_target: false,
+            source: SOURCE,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.init();
+        while let Some(next) = self.pending.pop_front() {
+            self.go_to(next);
+
+            if next == self.target {
+                self.visited.set(next.0, next.1, OXYGEN);
+
+                self.visited = Screen::new();
+                self.pending.clear();
+                self.predecesors.clear();
+                self.distances.clear();
+                self.source = next;
+                self.target = TARGET_NOT_FOUND;
+                self.no_target = true;
+                self.distances.insert(next, 0);
+                // break;
+            }
+
+            // if it was in the queue it is either an open space or the target
+            self.visited.set(next.0, next.1, MOVED);
+
+            for &dir in &Direction::ALL {
+                // explore each direction
+                let n = self.get_position(dir);
+                if self.visited.get(n.0, n.1).is_none() {
+                    let (pos, kind) = self.explore(dir);
+
+                    if kind == Wall {
+                        self.visited.set(pos.0, pos.1, WALL);
+                        continue;
+                    }
+
+                    let alt_distance = self.distances.get(&next).unwrap() + 1;
+                    if &alt_distance < self.distances.get(&pos).unwrap_or(&INFINITY) {
+                        self.distances.insert(pos, alt_distance);
+                        self.predecesors.insert(pos, dir.opposite());
+                        if !self.pending.contains(&pos) {
+                            self.pending.push_back(pos);
+                        }
+                    }
+
+                    if kind == Target {
+                        self.target = pos;
+                    }
+                }
+            }
+            self.update();
+        }
+    }
+
+    /// The cells found, relative to where the droid started, hanging up on the droid
+    pub fn into_map(self) -> Map {
+        let shift = |(x, y): (Int, Int)| (x - SOURCE.0, y - SOURCE.1);
+        let mut tiles: Screen = self
+            .visited
+            .iter()
+            .map(|(pos, tile)| (shift(pos), tile))
+            .collect();
+        // after finding the oxygen system the walk starts over from it
+        let oxygen = if self.no_target {
+            Some(shift(self.source))
+        } else {
+            None
+        };
+        if let Some((x, y)) = oxygen {
+            tiles.set(x, y, OXYGEN);
+        }
+        Map { tiles, oxygen }
+    }
+
+    fn init(&mut self) {
+        write!(
+            self.stdout,
+            "{}{}",
+            clear::All,
+            cursor::Goto(self.robot.0 as u16, self.robot.1 as u16)
+        )
+        .unwrap();
+        self.mark(Tile::Robot, None);
+        self.pending.push_front(self.robot);
+        self.distances.insert(self.robot, 0);
+        self.update();
+    }
+
+    fn update(&mut self) {
+        write!(self.stdout, "{}", cursor::Hide).unwrap();
+        self.stdout.flush().unwrap();
+    }
+
+    fn go_to(&mut self, pos: (Int, Int)) {
+        self.mark(OpenSpace, None);
+        // go back to source
+        while let Some(&dir) = self.predecesors.get(&self.robot) {
+            self.move_robot(dir);
+        }
+        assert_eq!(self.robot, self.source);
+
+        // go from source to pos
+        let mut current = pos;
+        let mut directions = vec![];
+        while let Some(&dir) = self.predecesors.get(&current) {
+            current = dir.step(current);
+            directions.push(dir.opposite());
+        }
+        for dir in directions.iter().rev() {
+            self.move_robot(*dir);
+        }
+
+        assert_eq!(self.robot, pos);
+        self.mark(Robot, None);
+    }
+
+    /// Explore returns what is found in the direction given by dir in the form
+    /// (x, y) is of kind k. Leaves the robot in its original position
+    fn explore(&mut self, dir: Direction) -> ((Int, Int), Tile) {
+        let robot_backup = self.robot;
+
+        let new_info = self.move_robot(dir);
+        if self.robot != robot_backup {
+            self.move_robot(dir.opposite());
+        }
+
+        new_info
+    }
+
+    /// moves the robot and returns the new information gathered in the form
+    /// (x, y) is of kind k:Tile
+    fn move_robot(&mut self, dir: Direction) -> ((Int, Int), Tile) {
+        self.robot_in.send(command(dir)).unwrap();
+        if let Ok(out) = self.robot_out.recv() {
+            return match out {
+                WALL => {
+                    self.mark(Wall, Some(dir));
+                    (self.get_position(dir), Wall)
+                }
+                MOVED => {
+                    self.mark(OpenSpace, None);
+                    self.robot = self.get_position(dir);
+                    (self.robot, OpenSpace)
+                }
+                OXYGEN => {
+                    self.mark(OpenSpace, None);
+                    if self.no_target {
+                        self.robot = self.get_position(dir);
+                    } else {
+                        self.target = self.get_position(dir);
+                        self.robot = self.target;
+                    }
+                    (self.robot, Target)
+                }
+                _ => panic!("robot got crazy: {}", out),
+            };
+        }
+        unreachable!("robot did not answer");
+    }
+
+    fn get_position(&self, dir: Direction) -> (Int, Int) {
+        dir.step(self.robot)
+    }
+
+    fn mark(&mut self, kind: Tile, dir: Option<Direction>) {
+        let (x, y) = if let Some(d) = dir {
+            self.get_position(d)
+        } else {
+            self.robot
+        };
+
+        write!(self.stdout, "{}", cursor::Goto(x as u16, y as u16)).unwrap();
+        // if in target paint the background cyan when over that tile,
+        // or the trophy otherwise
+        if (x, y) == self.target {
+            let cyan = color::Cyan;
+            if (x, y) == self.robot {
+                //background
+                write!(
+                    self.stdout,
+                    "{}*{}",
+                    color::Bg(cyan),
+                    color::Bg(color::Reset)
+                )
+                .unwrap();
+            } else {
+                // trophy icon
+                write!(
+                    self.stdout,
+                    "{}๏{}",
+                    color::Fg(cyan),
+                    color::Fg(color::Reset)
+                )
+                .unwrap();
+            }
+        } else {
+            let icon = match kind {
+                Tile::OpenSpace => '.',
+                Tile::Robot => '*',
+                Tile::Wall => '█',
+                Tile::Target => '๏',
+            };
+            write!(self.stdout, "{}", icon).unwrap();
+        }
+        write!(
+            self.stdout,
+            "{}",
+            cursor::Goto(self.robot.0 as u16, self.robot.1 as u16)
+        )
+        .unwrap();
+    }
+}
+
+/// Runs the explorer without a terminal, the droid program stops once the explorer hangs up
+pub fn explore(data: &[Int]) -> Map {
+    let (input_sender, input_receiver) = channel();
+    let (output_sender, output_receiver) = channel();
+    let mut program = Program::new(data, input_receiver, output_sender);
+    let droid = thread::spawn(move || program.run_until_blocked());
+
+    let mut explorer = Explorer::new(io::sink(), input_sender, output_receiver);
+    explorer.start();
+    let map = explorer.into_map();
+    droid.join().unwrap();
+    map
+}
+
+/// Fewest moves from the start to the oxygen system for part one, minutes for the oxygen to
+/// fill the area for part two. Both parts need the whole area explored, so callers wanting
+/// both should `explore` once and pass the map to `answer`
+pub fn solve(data: &[Int], part: Part) -> usize {
+    answer(&explore(data), part)
+}
+
+pub fn answer(map: &Map, part: Part) -> usize {
+    let oxygen = map.oxygen.expect("the droid found no oxygen system");
+    match part {
+        Part::One => map.distances((0, 0))[&oxygen],
+        Part::Two => map.distances(oxygen).values().cloned().max().unwrap_or(0),
+    }
+}
@@ -0,0 +1,34 @@
+extern crate repair_droid;
+use intcode::{get_data_from_path, Part};
+use repair_droid::{answer, explore, solve, Map, MOVED, OXYGEN, WALL};
+
+#[test]
+fn test_distances() {
+    // a corridor going right from the start with a branch going up at x = 1
+    let map = Map {
+        tiles: vec![(0, 0), (1, 0), (2, 0), (1, -1), (1, -2)]
+            .into_iter()
+            .map(|pos| (pos, MOVED))
+            .chain(vec![((0, -1), WALL)])
+            .collect(),
+        oxygen: Some((1, -2)),
+    };
+    let distances = map.distances((0, 0));
+    assert_eq!(distances[&(1, -2)], 3);
+    assert_eq!(distances[&(2, 0)], 2);
+    assert_eq!(map.distances((1, -2)).values().max(), Some(&3));
+}
+
+#[test]
+fn test_input() {
+    let data = get_data_from_path("data/input.txt");
+    let map = explore(&data);
+    assert_eq!(map.tiles.get(0, 0), Some(MOVED));
+    assert_eq!(
+        map.oxygen.and_then(|(x, y)| map.tiles.get(x, y)),
+        Some(OXYGEN)
+    );
+    assert_eq!(answer(&map, Part::One), 226);
+    assert_eq!(answer(&map, Part::Two), 342);
+    assert_eq!(solve(&data, Part::Two), 342);
+}
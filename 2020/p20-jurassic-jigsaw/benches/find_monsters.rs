@@ -0,0 +1,65 @@
+//! Times `find_monsters` on a pseudo random image against a scan looking cells up in a set,
+//! which is how the search worked before `DenseTile`. Run with `cargo bench`.
+
+use p20_jurassic_jigsaw::monster::{find_monsters, sea_monster};
+use p20_jurassic_jigsaw::Tile;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZE: usize = 96;
+const RUNS: u32 = 50;
+
+/// Sets about half of the cells of a `SIZE` x `SIZE` image, the same ones in every run
+fn random_image() -> Tile {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..SIZE)
+        .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+        .filter(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & 1 == 1
+        })
+        .collect()
+}
+
+fn set_scan(image: &Tile, monster: &Tile) -> usize {
+    let width = monster.iter().map(|pos| pos.0).max().unwrap();
+    let height = monster.iter().map(|pos| pos.1).max().unwrap();
+    image
+        .orientations()
+        .map(|oriented| {
+            let board: HashSet<_> = oriented.iter().cloned().collect();
+            (0..SIZE - height)
+                .flat_map(|y| (0..SIZE - width).map(move |x| (x, y)))
+                .filter(|&(dx, dy)| {
+                    monster
+                        .iter()
+                        .all(|(x, y)| board.contains(&(x + dx, y + dy)))
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn time(f: impl Fn()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        f();
+    }
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    let image = random_image();
+    let monster = sea_monster();
+    let dense = time(|| {
+        black_box(find_monsters(black_box(&image), &monster));
+    });
+    let set = time(|| {
+        black_box(set_scan(black_box(&image), &monster));
+    });
+    println!("find_monsters {:?} per call", dense);
+    println!("set scan      {:?} per call", set);
+}
@@ -1,6 +1,6 @@
 use crate::Tile;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Border {
     U,
     D,
@@ -8,9 +8,10 @@ pub enum Border {
     L,
 }
 pub const BORDERS: [Border; 4] = [Border::R, Border::L, Border::U, Border::D];
+/// Borders in the order used by `edges`
+pub const EDGE_ORDER: [Border; 4] = [Border::U, Border::R, Border::D, Border::L];
 
 impl Border {
-    #[allow(clippy::ptr_arg)]
     pub fn get(&self, tile: &Tile) -> Vec<usize> {
         let max_x = tile.iter().map(|p| p.0).max().unwrap();
         let max_y = tile.iter().map(|p| p.1).max().unwrap();
@@ -50,3 +51,67 @@ impl Border {
         }
     }
 }
+
+/// Width and height of the puzzle tiles, borders included
+pub const TILE_SIZE: usize = 10;
+
+/// Encodes the top, right, bottom and left edges of a `TILE_SIZE` wide tile as bits, set cells are
+/// 1s. Edges are read left to right and top to bottom, with the first cell as the most significant
+/// bit, so the touching edges of two neighbours in the puzzle are encoded equally. Returns `None`
+/// if a cell lies outside the `TILE_SIZE` x `TILE_SIZE` square.
+pub fn edges(tile: &Tile) -> Option<[u16; 4]> {
+    let last = TILE_SIZE - 1;
+    if tile.iter().any(|&(x, y)| x > last || y > last) {
+        return None;
+    }
+    let mut edges = [0_u16; 4];
+    for &(x, y) in tile {
+        if y == 0 {
+            edges[0] |= 1 << (last - x);
+        }
+        if x == last {
+            edges[1] |= 1 << (last - y);
+        }
+        if y == last {
+            edges[2] |= 1 << (last - x);
+        }
+        if x == 0 {
+            edges[3] |= 1 << (last - y);
+        }
+    }
+    Some(edges)
+}
+
+/// Reads an encoded edge in the oposite direction
+pub fn reverse_edge(edge: u16) -> u16 {
+    edge.reverse_bits() >> (16 - TILE_SIZE)
+}
+
+/// Like `edges` but each edge is the smallest of its encoding and the reversed one, so an edge
+/// matches its neighbour's regardless of how either tile is flipped.
+pub fn edges_canonical(tile: &Tile) -> Option<[u16; 4]> {
+    let mut edges = edges(tile)?;
+    for edge in edges.iter_mut() {
+        *edge = (*edge).min(reverse_edge(*edge));
+    }
+    Some(edges)
+}
+
+/// Finds an edge of `a` that matches one of `b`. Returns the border of `a`, the border of `b` and
+/// whether `b`'s edge has to be flipped so both edges read the same in the `edges` encoding.
+/// Tiles that do not fit in `TILE_SIZE` match nothing.
+pub fn find_matching_edge(a: &Tile, b: &Tile) -> Option<(Border, Border, bool)> {
+    let edges_a = edges(a)?;
+    let edges_b = edges(b)?;
+    for (border_a, edge_a) in EDGE_ORDER.iter().zip(edges_a.iter()) {
+        for (border_b, edge_b) in EDGE_ORDER.iter().zip(edges_b.iter()) {
+            if edge_a == edge_b {
+                return Some((*border_a, *border_b, false));
+            }
+            if *edge_a == reverse_edge(*edge_b) {
+                return Some((*border_a, *border_b, true));
+            }
+        }
+    }
+    None
+}
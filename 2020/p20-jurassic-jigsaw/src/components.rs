@@ -0,0 +1,109 @@
+use crate::{Position, Tile};
+use std::collections::{HashSet, VecDeque};
+
+/// The positions next to `pos` that do not go below 0, diagonals included if `eight_connected`
+fn neighbours(pos: Position, eight_connected: bool) -> impl Iterator<Item = Position> {
+    let (x, y) = pos;
+    (-1_i64..=1)
+        .flat_map(|dy| (-1_i64..=1).map(move |dx| (dx, dy)))
+        .filter(move |&(dx, dy)| (dx, dy) != (0, 0) && (eight_connected || dx == 0 || dy == 0))
+        .filter_map(move |(dx, dy)| {
+            let x = x as i64 + dx;
+            let y = y as i64 + dy;
+            if x < 0 || y < 0 {
+                None
+            } else {
+                Some((x as usize, y as usize))
+            }
+        })
+}
+
+/// Visits every cell connected to `start`, marking them as seen
+fn spread(
+    cells: &HashSet<Position>,
+    start: Position,
+    eight_connected: bool,
+    seen: &mut HashSet<Position>,
+    mut visit: impl FnMut(Position),
+) {
+    let mut queue = VecDeque::new();
+    seen.insert(start);
+    queue.push_back(start);
+    while let Some(pos) = queue.pop_front() {
+        visit(pos);
+        for next in neighbours(pos, eight_connected) {
+            if cells.contains(&next) && seen.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+}
+
+/// Collects every cell connected to `start`, marking them as seen
+fn fill(
+    cells: &HashSet<Position>,
+    start: Position,
+    eight_connected: bool,
+    seen: &mut HashSet<Position>,
+) -> Tile {
+    let mut component = Vec::new();
+    spread(cells, start, eight_connected, seen, |pos| {
+        component.push(pos)
+    });
+    component.sort_unstable();
+    Tile::from(component)
+}
+
+/// Splits the set cells into groups of connected cells, either through their sides only or also
+/// through their corners if `eight_connected`. Components are sorted by their first cell.
+pub fn components(tile: &Tile, eight_connected: bool) -> Vec<Tile> {
+    let cells: HashSet<_> = tile.iter().cloned().collect();
+    let mut sorted: Vec<_> = cells.iter().cloned().collect();
+    sorted.sort_unstable();
+
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+    for pos in sorted {
+        if !seen.contains(&pos) {
+            components.push(fill(&cells, pos, eight_connected, &mut seen));
+        }
+    }
+    components
+}
+
+/// How many components `components` would return, without building them
+pub fn count_components(tile: &Tile, eight_connected: bool) -> usize {
+    let cells: HashSet<_> = tile.iter().cloned().collect();
+    let mut seen = HashSet::new();
+    let mut count = 0;
+    for &pos in &cells {
+        if !seen.contains(&pos) {
+            spread(&cells, pos, eight_connected, &mut seen, |_| {});
+            count += 1;
+        }
+    }
+    count
+}
+
+/// The component that contains `start`, empty if `start` is not a set cell
+pub fn flood(tile: &Tile, start: Position, eight_connected: bool) -> Tile {
+    let cells: HashSet<_> = tile.iter().cloned().collect();
+    if !cells.contains(&start) {
+        return Tile::new();
+    }
+    fill(&cells, start, eight_connected, &mut HashSet::new())
+}
+
+/// The set cells with at least one empty side, cells on the axes always count as having one
+pub fn boundary(tile: &Tile) -> Tile {
+    let cells: HashSet<_> = tile.iter().cloned().collect();
+    let mut boundary: Tile = cells
+        .iter()
+        .filter(|&&(x, y)| {
+            x == 0 || y == 0 || neighbours((x, y), false).any(|next| !cells.contains(&next))
+        })
+        .cloned()
+        .collect();
+    boundary.sort_unstable();
+    boundary
+}
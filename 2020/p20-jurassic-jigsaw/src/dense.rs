@@ -0,0 +1,120 @@
+use crate::{Position, Tile};
+
+/// The tile as rows of cells, from the origin to its furthest cells
+pub fn to_grid(tile: &Tile) -> Vec<Vec<bool>> {
+    let (max_x, max_y) = match (
+        tile.iter().map(|pos| pos.0).max(),
+        tile.iter().map(|pos| pos.1).max(),
+    ) {
+        (Some(max_x), Some(max_y)) => (max_x, max_y),
+        _ => return Vec::new(),
+    };
+    let mut grid = vec![vec![false; max_x + 1]; max_y + 1];
+    for &(x, y) in tile {
+        grid[y][x] = true;
+    }
+    grid
+}
+
+/// The set cells of the rows, sorted like a normalized tile
+pub fn from_grid(grid: &[Vec<bool>]) -> Tile {
+    let mut tile: Tile = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(move |(x, &set)| if set { Some((x, y)) } else { None })
+        })
+        .collect();
+    tile.sort_unstable();
+    tile
+}
+
+/// A tile stored as one bit per cell, row by row, from the origin to its furthest cells. Looking
+/// up a cell is much cheaper than in a `Tile`, which matters when scanning big images.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseTile {
+    width: usize,
+    height: usize,
+    bits: Vec<u64>,
+}
+
+impl DenseTile {
+    pub fn new(width: usize, height: usize) -> Self {
+        DenseTile {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the cell is set, cells outside the tile are never set
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let i = y * self.width + x;
+        self.bits[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Sets or clears the cell, panics if it is outside the tile
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the {}x{} tile",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        let i = y * self.width + x;
+        if value {
+            self.bits[i / 64] |= 1 << (i % 64);
+        } else {
+            self.bits[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(move |&(x, y)| self.get(x, y))
+    }
+}
+
+impl From<&Tile> for DenseTile {
+    fn from(tile: &Tile) -> Self {
+        let width = tile.iter().map(|pos| pos.0 + 1).max().unwrap_or(0);
+        let height = tile.iter().map(|pos| pos.1 + 1).max().unwrap_or(0);
+        let mut dense = DenseTile::new(width, height);
+        for &(x, y) in tile {
+            dense.set(x, y, true);
+        }
+        dense
+    }
+}
+
+impl From<&DenseTile> for Tile {
+    /// The set cells, sorted like a normalized tile
+    fn from(dense: &DenseTile) -> Self {
+        let mut tile: Tile = dense.iter().collect();
+        tile.sort_unstable();
+        tile
+    }
+}
+
+impl From<DenseTile> for Tile {
+    fn from(dense: DenseTile) -> Self {
+        Tile::from(&dense)
+    }
+}
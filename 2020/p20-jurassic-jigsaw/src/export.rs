@@ -0,0 +1,54 @@
+use crate::Tile;
+#[cfg(feature = "image")]
+use std::path::Path;
+
+/// Saves the tile as a grayscale png, set cells are white and empty ones black. Each cell takes
+/// `scale` x `scale` pixels.
+#[cfg(feature = "image")]
+pub fn write_png<P: AsRef<Path>>(tile: &Tile, scale: u32, path: P) -> image::ImageResult<()> {
+    use image::{GrayImage, Luma};
+
+    let scale = scale.max(1);
+    let (max_x, max_y) = tile
+        .iter()
+        .fold((0_usize, 0_usize), |(max_x, max_y), (x, y)| {
+            (max_x.max(*x), max_y.max(*y))
+        });
+
+    let mut img = GrayImage::new((max_x as u32 + 1) * scale, (max_y as u32 + 1) * scale);
+    for &(x, y) in tile {
+        for dy in 0..scale {
+            for dx in 0..scale {
+                img.put_pixel(x as u32 * scale + dx, y as u32 * scale + dy, Luma([255]));
+            }
+        }
+    }
+    img.save(path)
+}
+
+/// Renders the tile as an svg document with one black `cell` x `cell` square per set cell.
+pub fn tile_to_svg(tile: &Tile, cell: usize) -> String {
+    let (width, height) = match (
+        tile.iter().map(|pos| pos.0).max(),
+        tile.iter().map(|pos| pos.1).max(),
+    ) {
+        (Some(max_x), Some(max_y)) => ((max_x + 1) * cell, (max_y + 1) * cell),
+        _ => (0, 0),
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+        w = width,
+        h = height
+    );
+    for (x, y) in tile {
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{c}\" height=\"{c}\"/>\n",
+            x * cell,
+            y * cell,
+            c = cell
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
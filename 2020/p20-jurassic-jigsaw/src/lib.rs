@@ -0,0 +1,17 @@
+pub mod border;
+pub mod components;
+pub mod dense;
+pub mod export;
+pub mod monster;
+pub mod op;
+pub mod paint;
+pub mod parse;
+pub mod puzzle;
+pub mod set;
+pub mod symmetry;
+mod tile;
+pub mod transform;
+
+pub type TileId = u64;
+pub type Position = (usize, usize);
+pub use tile::Tile;
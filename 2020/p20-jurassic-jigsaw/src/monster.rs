@@ -0,0 +1,87 @@
+use crate::dense::DenseTile;
+use crate::op::{Operation, OPERATIONS};
+use crate::set::difference;
+use crate::{parse, Position, Tile};
+use std::collections::BTreeSet;
+
+const SEA_MONSTER: &str = "Tile 1:
+                  #
+#    ##    ##    ###
+ #  #  #  #  #  #";
+
+pub fn sea_monster() -> Tile {
+    parse::parse_tile(SEA_MONSTER)
+        .expect("the sea monster is a valid tile")
+        .1
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monsters {
+    /// The image in the orientation in which the monsters were found
+    pub image: Tile,
+    /// Top left corner of each monster found
+    pub positions: Vec<Position>,
+    /// Every cell covered by a monster, without repetitions
+    pub cells: Tile,
+}
+
+impl Monsters {
+    /// The set cells of the image that are not part of any monster
+    pub fn roughness(&self) -> usize {
+        difference(&self.image, &self.cells).len()
+    }
+}
+
+/// Slides the monster over the image looking for places where all its cells are set, trying
+/// every orientation of the image until one has monsters. If no orientation has any, the image
+/// is returned as is and without positions.
+pub fn find_monsters(image: &Tile, monster: &Tile) -> Monsters {
+    let monster_width = monster.iter().map(|pos| pos.0).max().unwrap_or(0);
+    let monster_height = monster.iter().map(|pos| pos.1).max().unwrap_or(0);
+
+    for op in &OPERATIONS {
+        let try_image = op.operate_clone(image);
+        let board = DenseTile::from(&try_image);
+        let (max_x, max_y) = match (
+            board.width().checked_sub(monster_width + 1),
+            board.height().checked_sub(monster_height + 1),
+        ) {
+            (Some(max_x), Some(max_y)) => (max_x, max_y),
+            _ => continue, // the monster does not fit in this orientation of the image
+        };
+
+        let mut positions = Vec::new();
+        let mut cells = BTreeSet::new();
+        for y_shift in 0..=max_y {
+            for x_shift in 0..=max_x {
+                // check that the shifted monster tiles are contained in the board
+                if monster
+                    .iter()
+                    .all(|(x, y)| board.get(x + x_shift, y + y_shift))
+                {
+                    positions.push((x_shift, y_shift));
+                    cells.extend(monster.iter().map(|(x, y)| (x + x_shift, y + y_shift)));
+                }
+            }
+        }
+        if !positions.is_empty() {
+            return Monsters {
+                image: try_image,
+                positions,
+                cells: cells.into_iter().collect(),
+            };
+        }
+    }
+
+    Monsters {
+        image: image.clone(),
+        positions: Vec::new(),
+        cells: Tile::new(),
+    }
+}
+
+/// Counts the set cells of the image that are not part of any monster, cells shared by
+/// overlapping monsters are only discounted once.
+pub fn water_roughness(image: &Tile, monster: &Tile) -> usize {
+    find_monsters(image, monster).roughness()
+}
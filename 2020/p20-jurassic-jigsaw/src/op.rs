@@ -51,7 +51,6 @@ pub struct Op {
 }
 
 pub trait Operation {
-    #[allow(clippy::ptr_arg)]
     fn operate_clone(&self, tile: &Tile) -> Tile {
         let mut tile = tile.to_owned();
         self.operate(&mut tile);
@@ -62,9 +61,8 @@ pub trait Operation {
 
 impl Operation for Flip {
     fn operate(&self, tile: &mut Tile) {
-        let width = *tile.iter().map(|(x, _y)| x).max().unwrap();
-        for (x, _) in tile.iter_mut() {
-            *x = width - *x;
+        if let Some(&width) = tile.iter().map(|(x, _y)| x).max() {
+            tile.map_cells(|(x, y)| (width - x, y));
         }
     }
 }
@@ -80,9 +78,7 @@ impl Operation for Rotate {
             // rotate is transpose and then horizontal flip
 
             // transpose:
-            for pos in tile.iter_mut() {
-                *pos = (pos.1, pos.0);
-            }
+            tile.map_cells(|(x, y)| (y, x));
             // and flip
             Operation::operate(&Flip, tile);
         }
@@ -99,3 +95,8 @@ impl Operation for Op {
         }
     }
 }
+
+/// The eight ways of placing the tile, one per operation
+pub fn orientations(tile: &Tile) -> impl Iterator<Item = Tile> + '_ {
+    OPERATIONS.iter().map(move |op| op.operate_clone(tile))
+}
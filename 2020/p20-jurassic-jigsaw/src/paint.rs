@@ -1,57 +1,238 @@
-use crate::Tile;
-use std::collections::HashSet;
+use crate::parse::tile_to_string;
+use crate::{Position, Tile};
+use grid::{render_grid_styled, render_set, GridStyle};
+use std::collections::{HashMap, HashSet};
+
+/// How a tile is drawn: each cell is `cell_width` copies of the `set`, `empty` or `highlight`
+/// char, optionally surrounded by a frame and with the coordinates of the rows and columns.
+#[derive(Debug, Clone)]
+pub struct PaintStyle {
+    pub cell_width: usize,
+    pub set: char,
+    pub empty: char,
+    pub highlight: char,
+    pub border: bool,
+    pub ruler: bool,
+}
+
+impl Default for PaintStyle {
+    fn default() -> Self {
+        PaintStyle {
+            cell_width: 2,
+            set: '█',
+            empty: ' ',
+            highlight: 'O',
+            border: false,
+            ruler: false,
+        }
+    }
+}
+
+impl PaintStyle {
+    pub fn with_border(mut self) -> Self {
+        self.border = true;
+        self
+    }
+
+    pub fn with_ruler(mut self) -> Self {
+        self.ruler = true;
+        self
+    }
+}
 
-#[allow(clippy::ptr_arg)]
 pub fn paint_tile(tile: &Tile) {
-    let (max_x, max_y) = tile
+    let cells: HashSet<(i64, i64)> = tile.iter().map(|&(x, y)| (x as i64, y as i64)).collect();
+    println!("{}", render_set(&cells));
+}
+
+/// Draws the tile with the default chars, `cell_width` of them per cell. An empty tile is an
+/// empty string
+pub fn render_tile(tile: &Tile, cell_width: usize) -> String {
+    let default = PaintStyle::default();
+    let cells: HashMap<(i64, i64), ()> = tile
         .iter()
-        .fold((0_usize, 0_usize), |(max_x, max_y), (x, y)| {
-            (max_x.max(*x), max_y.max(*y))
-        });
+        .map(|&(x, y)| ((x as i64, y as i64), ()))
+        .collect();
+    let style = GridStyle {
+        cell_width,
+        background: default.empty,
+        from_origin: true,
+        ..GridStyle::default()
+    };
+    render_grid_styled(&cells, |_| default.set, &style)
+}
 
-    let mut paint = String::with_capacity(max_y * max_x);
-    for y in 0..=max_y {
-        for x in 0..=max_x {
-            if tile.contains(&(x, y)) {
-                paint.push('█');
-                paint.push('█');
-            } else {
-                paint.push(' ');
-                paint.push(' ');
-            }
-        }
+pub fn paint_tile_styled(tile: &Tile, style: &PaintStyle) -> String {
+    paint(&tile.iter().cloned().collect(), &HashSet::new(), style)
+}
+
+/// Paints the tile using the style's `highlight` char for the highlighted cells, like the
+/// monsters found in the image.
+pub fn paint_tile_highlighted(tile: &Tile, highlighted: &Tile, style: &PaintStyle) -> String {
+    paint(
+        &tile.iter().cloned().collect(),
+        &highlighted.iter().cloned().collect(),
+        style,
+    )
+}
+
+/// Paints the tiles side by side, each one with its label on top
+pub fn paint_gallery(tiles: &[(String, &Tile)], style: &PaintStyle) -> String {
+    const GAP: &str = "  ";
+    let columns: Vec<Vec<String>> = tiles
+        .iter()
+        .map(|(label, tile)| {
+            let mut column = vec![label.to_owned()];
+            column.extend(paint_tile_styled(tile, style).lines().map(String::from));
+            column
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .map(|column| {
+            column
+                .iter()
+                .map(|row| row.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let height = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+
+    let mut paint = String::new();
+    for y in 0..height {
+        // columns after the last one with a row here would only add padding, and that one is
+        // left as painted so its empty cells keep lining up
+        let last = columns
+            .iter()
+            .rposition(|column| y < column.len())
+            .unwrap_or(0);
+        let row: Vec<String> = columns[..=last]
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (column, &width))| {
+                let cell = column.get(y).map(String::as_str).unwrap_or("");
+                if i == last {
+                    cell.to_owned()
+                } else {
+                    format!("{:width$}", cell, width = width)
+                }
+            })
+            .collect();
+        paint.push_str(&row.join(GAP));
         paint.push('\n');
     }
+    paint
+}
 
-    println!("{}", paint);
+/// Paints the tiles one below the other, separated by a dashed line as wide as the widest tile.
+/// Each tile is written with `tile_to_string` and drawn with the style's `set` and `empty` chars,
+/// `cell_width` of them per cell.
+pub fn paint_stack(tiles: &[&Tile], style: &PaintStyle) -> String {
+    let paints: Vec<String> = tiles
+        .iter()
+        .map(|tile| {
+            tile_to_string(tile)
+                .chars()
+                .map(|c| match c {
+                    '#' => style.set.to_string().repeat(style.cell_width),
+                    '.' => style.empty.to_string().repeat(style.cell_width),
+                    _ => c.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    let width = paints
+        .iter()
+        .flat_map(|paint| paint.lines())
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let separator = "-".repeat(width) + "\n";
+    paints.join(&separator)
 }
 
 pub fn paint_tile_with_monsters(
     tile: &HashSet<(usize, usize)>,
     tiles_with_monsters: &HashSet<(usize, usize)>,
 ) {
+    let style = PaintStyle {
+        set: '~',
+        highlight: '█',
+        ..PaintStyle::default()
+    };
+    println!("{}", paint(tile, tiles_with_monsters, &style));
+}
+
+fn paint(tile: &HashSet<Position>, highlighted: &HashSet<Position>, style: &PaintStyle) -> String {
+    if tile.is_empty() && highlighted.is_empty() {
+        return String::new();
+    }
     let (max_x, max_y) = tile
         .iter()
+        .chain(highlighted)
         .fold((0_usize, 0_usize), |(max_x, max_y), (x, y)| {
             (max_x.max(*x), max_y.max(*y))
         });
 
+    // the y coordinates go before each row, so everything else is moved to the right
+    let margin = if style.ruler {
+        max_y.to_string().len() + 1
+    } else {
+        0
+    };
+    let frame = "─".repeat((max_x + 1) * style.cell_width);
     let mut paint = String::with_capacity(max_y * max_x);
+    if style.ruler {
+        // one line per digit of the x coordinates, read top to bottom
+        let digits = max_x.to_string().len();
+        for place in (0..digits).rev() {
+            let mut line = " ".repeat(margin + style.border as usize);
+            for x in 0..=max_x {
+                let digit = (x / 10_usize.pow(place as u32)) % 10;
+                line.push_str(&format!("{:width$}", digit, width = style.cell_width));
+            }
+            paint.push_str(line.trim_end());
+            paint.push('\n');
+        }
+    }
+    if style.border {
+        paint.push_str(&" ".repeat(margin));
+        paint.push('┌');
+        paint.push_str(&frame);
+        paint.push_str("┐\n");
+    }
     for y in 0..=max_y {
+        if style.ruler {
+            paint.push_str(&format!("{:>width$} ", y, width = margin - 1));
+        }
+        if style.border {
+            paint.push('│');
+        }
         for x in 0..=max_x {
-            if tiles_with_monsters.contains(&(x, y)) {
-                paint.push('█');
-                paint.push('█');
+            let c = if highlighted.contains(&(x, y)) {
+                style.highlight
             } else if tile.contains(&(x, y)) {
-                paint.push('~');
-                paint.push('~');
+                style.set
             } else {
-                paint.push(' ');
-                paint.push(' ');
+                style.empty
+            };
+            for _ in 0..style.cell_width {
+                paint.push(c);
             }
         }
+        if style.border {
+            paint.push('│');
+        }
         paint.push('\n');
     }
+    if style.border {
+        paint.push_str(&" ".repeat(margin));
+        paint.push('└');
+        paint.push_str(&frame);
+        paint.push_str("┘\n");
+    }
 
-    println!("{}", paint);
+    paint
 }
@@ -1,32 +1,110 @@
 use crate::{Tile, TileId};
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
 use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
 
-pub fn parse_tile(data: &str) -> (TileId, Tile) {
-    let mut lines = data.lines();
-    let tile_id: TileId = lines
-        .next()
-        .expect("tile has a tile id line")
-        .split(|c| c == ' ' || c == ':')
-        .nth(1)
-        .expect("tile id line has id")
-        .parse()
-        .unwrap();
-    let tiles = lines
+/// Ways the puzzle input can be malformed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// There is a block of lines without the `Tile <id>:` line
+    MissingHeader,
+    /// The first line of a tile is not `Tile <id>:`
+    BadHeader(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingHeader => write!(f, "tile has no tile id line"),
+            ParseError::BadHeader(line) => write!(f, "bad tile id line {:?}", line),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_tile(data: &str) -> Result<(TileId, Tile), ParseError> {
+    parse_block(data.lines())
+}
+
+/// The `Tile <id>:` line followed by the grid
+fn parse_block<'a>(mut lines: impl Iterator<Item = &'a str>) -> Result<(TileId, Tile), ParseError> {
+    let header = lines.next().ok_or(ParseError::MissingHeader)?.trim();
+    let tile_id = header
+        .strip_prefix("Tile ")
+        .and_then(|rest| rest.strip_suffix(':'))
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| ParseError::BadHeader(header.to_owned()))?;
+    Ok((tile_id, parse_grid(lines)))
+}
+
+/// Reads the set cells (`#`) of the lines, anything else is an empty cell
+fn parse_grid<'a>(lines: impl Iterator<Item = &'a str>) -> Tile {
+    lines
         .enumerate()
         .flat_map(|(y, l)| {
             l.char_indices()
                 .filter_map(move |(x, c)| if c == '#' { Some((x, y)) } else { None })
         })
-        .collect();
+        .collect()
+}
 
-    (tile_id, tiles)
+/// Parses every tile of the puzzle input, tiles are separated by one or more blank lines
+pub fn parse_tiles(input: &str) -> Result<HashMap<TileId, Tile>, ParseError> {
+    let mut tiles = HashMap::new();
+    let mut lines = input.lines().peekable();
+    loop {
+        while lines.peek().is_some_and(|line| line.trim().is_empty()) {
+            lines.next();
+        }
+        if lines.peek().is_none() {
+            return Ok(tiles);
+        }
+        let (tile_id, tile) =
+            parse_block(lines.by_ref().take_while(|line| !line.trim().is_empty()))?;
+        tiles.insert(tile_id, tile);
+    }
 }
 
+/// Panics if the file can not be read or parsed
 pub fn parse_tile_file(path: &str) -> HashMap<TileId, Tile> {
-    read_to_string(path)
-        .expect("bad file")
-        .split("\n\n")
-        .map(|part| parse_tile(part))
+    parse_tiles(&read_to_string(path).expect("bad file"))
+        .unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+/// Writes the tile in the same format as the puzzle input, without the tile id line
+pub fn tile_to_string(tile: &Tile) -> String {
+    let (max_x, max_y) = match (
+        tile.iter().map(|pos| pos.0).max(),
+        tile.iter().map(|pos| pos.1).max(),
+    ) {
+        (Some(max_x), Some(max_y)) => (max_x, max_y),
+        _ => return String::new(),
+    };
+    let mut grid = vec![vec!['.'; max_x + 1]; max_y + 1];
+    for &(x, y) in tile {
+        grid[y][x] = '#';
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
         .collect()
 }
+
+/// Id written in the `Tile <id>:` line of saved tiles, which have no id of their own
+pub const SAVED_TILE_ID: TileId = 0;
+
+/// Writes the tile as a puzzle input with a single tile, so `parse_tile` and `parse_tile_file`
+/// can read it back
+pub fn save_tile(tile: &Tile, path: &Path) -> io::Result<()> {
+    let header = format!("Tile {}:\n", SAVED_TILE_ID);
+    std::fs::write(path, header + &tile_to_string(tile))
+}
+
+pub fn load_tile(path: &Path) -> io::Result<Tile> {
+    parse_tile(&read_to_string(path)?)
+        .map(|(_, tile)| tile)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
@@ -0,0 +1,198 @@
+use crate::border::{edges, edges_canonical, find_matching_edge, Border, EDGE_ORDER, TILE_SIZE};
+use crate::monster::{find_monsters, sea_monster, Monsters};
+use crate::op::orientations;
+use crate::parse::{parse_tiles, ParseError};
+use crate::transform::{assemble, crop};
+use crate::{Position, Tile, TileId};
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+/// How many tiles have each edge, in the `edges_canonical` encoding so an edge and its reverse
+/// count together
+fn edge_count(tiles: &HashMap<TileId, Tile>) -> HashMap<u16, usize> {
+    let mut edge_count: HashMap<u16, usize> = HashMap::new();
+    for edges in tiles.values().filter_map(edges_canonical) {
+        for edge in edges.iter() {
+            *edge_count.entry(*edge).or_default() += 1;
+        }
+    }
+    edge_count
+}
+
+/// The tiles with two edges that match no other tile, sorted. If there are not exactly four of
+/// them the tiles do not make a clean rectangle and no corners are returned. Tiles too big for
+/// `TILE_SIZE` are never corners.
+pub fn find_corners(tiles: &HashMap<TileId, Tile>) -> Vec<TileId> {
+    let edge_count = edge_count(tiles);
+
+    let mut corners: Vec<TileId> = tiles
+        .iter()
+        .filter(|(_, tile)| {
+            edges_canonical(tile)
+                .is_some_and(|edges| edges.iter().filter(|edge| edge_count[edge] == 1).count() == 2)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    if corners.len() != 4 {
+        return Vec::new();
+    }
+    corners.sort_unstable();
+    corners
+}
+
+/// For each tile, its neighbours and the border of the tile they are attached to, as the tiles
+/// are currently oriented.
+pub fn adjacency(tiles: &HashMap<TileId, Tile>) -> HashMap<TileId, Vec<(Border, TileId)>> {
+    let mut ids: Vec<TileId> = tiles.keys().cloned().collect();
+    ids.sort_unstable();
+
+    let mut adjacency: HashMap<TileId, Vec<(Border, TileId)>> =
+        ids.iter().map(|&id| (id, Vec::new())).collect();
+    for (i, &id_a) in ids.iter().enumerate() {
+        for &id_b in &ids[i + 1..] {
+            if let Some((border_a, border_b, _)) = find_matching_edge(&tiles[&id_a], &tiles[&id_b])
+            {
+                adjacency.get_mut(&id_a).unwrap().push((border_a, id_b));
+                adjacency.get_mut(&id_b).unwrap().push((border_b, id_a));
+            }
+        }
+    }
+    for neighbours in adjacency.values_mut() {
+        neighbours.sort_unstable();
+    }
+    adjacency
+}
+
+/// Places every tile in the puzzle, starting from a corner and walking the adjacency graph.
+/// Gives each tile, oriented to fit its neighbours, by its position in the grid of tiles, or
+/// `None` if the tiles do not fit together.
+fn place_tiles(tiles: &HashMap<TileId, Tile>) -> Option<HashMap<Position, Tile>> {
+    let adjacency = adjacency(tiles);
+    let first = *find_corners(tiles).first()?;
+
+    // orient the first corner so its neighbours are to the right and below it
+    let edge_count = edge_count(tiles);
+    let corner = orientations(&tiles[&first]).find(|oriented| {
+        edges_canonical(oriented)
+            .is_some_and(|edges| edge_count[&edges[0]] == 1 && edge_count[&edges[3]] == 1)
+    })?;
+
+    let mut placed: HashMap<TileId, (Position, Tile)> = HashMap::new();
+    placed.insert(first, ((0, 0), corner));
+    let mut queue = VecDeque::new();
+    queue.push_back(first);
+    while let Some(id) = queue.pop_front() {
+        let (position, tile) = placed[&id].clone();
+        let tile_edges = edges(&tile)?;
+        for (_, neighbour) in &adjacency[&id] {
+            if placed.contains_key(neighbour) {
+                continue;
+            }
+            // find the border of the placed tile and the orientation of the neighbour that fit
+            let (border, oriented) = orientations(&tiles[neighbour]).find_map(|oriented| {
+                let neighbour_edges = edges(&oriented)?;
+                (0..4)
+                    .find(|&i| tile_edges[i] == neighbour_edges[(i + 2) % 4])
+                    .map(|i| (EDGE_ORDER[i], oriented))
+            })?;
+            if (border == Border::U && position.1 == 0) || (border == Border::L && position.0 == 0)
+            {
+                return None;
+            }
+            placed.insert(*neighbour, (border.position_at_border(&position), oriented));
+            queue.push_back(*neighbour);
+        }
+    }
+    if placed.len() != tiles.len() {
+        return None;
+    }
+    Some(placed.into_values().collect())
+}
+
+/// Stitches the placed tiles without their borders into the final image
+fn stitch(placed: &HashMap<Position, Tile>) -> Tile {
+    let last = TILE_SIZE - 2;
+    let grid: HashMap<Position, Tile> = placed
+        .iter()
+        .map(|(&position, tile)| (position, crop(tile, (1, 1), (last, last))))
+        .collect();
+    assemble(&grid, TILE_SIZE - 2)
+}
+
+/// Places every tile in the puzzle and stitches them without their borders into the final
+/// image. Returns `None` if the tiles do not fit together.
+pub fn assemble_puzzle(tiles: &HashMap<TileId, Tile>) -> Option<Tile> {
+    place_tiles(tiles).map(|placed| stitch(&placed))
+}
+
+/// Ways the puzzle can not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The tiles do not make a rectangle whose touching edges match
+    NoFit,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::NoFit => write!(f, "the tiles do not fit together"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(e) => Some(e),
+            SolveError::NoFit => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// The answers and what they were worked out from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// The product of the corner tile ids
+    pub part_1: u64,
+    /// The water roughness of the image
+    pub part_2: usize,
+    pub corners: Vec<TileId>,
+    /// The placed tiles with their borders, neighbours overlapping on the edge they share
+    pub puzzle: Tile,
+    /// The tiles stitched without their borders, where the monsters are searched
+    pub image: Tile,
+    /// The sea monsters found in the image, with the image turned the way they were found
+    pub monsters: Monsters,
+}
+
+/// Solves both parts of the puzzle, keeping the corners and the assembled pictures.
+pub fn solution(input: &str) -> Result<Solution, SolveError> {
+    let tiles = parse_tiles(input)?;
+    let placed = place_tiles(&tiles).ok_or(SolveError::NoFit)?;
+    let corners = find_corners(&tiles);
+    let image = stitch(&placed);
+    let monsters = find_monsters(&image, &sea_monster());
+    Ok(Solution {
+        part_1: corners.iter().product(),
+        part_2: monsters.roughness(),
+        corners,
+        puzzle: assemble(&placed, TILE_SIZE - 1),
+        image,
+        monsters,
+    })
+}
+
+/// Solves both parts of the puzzle: the product of the corner tile ids and the water roughness of
+/// the assembled image.
+pub fn solve(input: &str) -> Result<(u64, usize), SolveError> {
+    solution(input).map(|solution| (solution.part_1, solution.part_2))
+}
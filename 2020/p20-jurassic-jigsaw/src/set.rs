@@ -0,0 +1,36 @@
+use crate::Tile;
+use std::collections::{BTreeSet, HashSet};
+
+// All of these return the cells sorted and without repetitions
+
+/// Cells in `a` or `b`
+pub fn union(a: &Tile, b: &Tile) -> Tile {
+    let cells: BTreeSet<_> = a.iter().chain(b).cloned().collect();
+    cells.into_iter().collect()
+}
+
+/// Cells in both `a` and `b`
+pub fn intersection(a: &Tile, b: &Tile) -> Tile {
+    let b: HashSet<_> = b.iter().collect();
+    let cells: BTreeSet<_> = a.iter().filter(|pos| b.contains(pos)).cloned().collect();
+    cells.into_iter().collect()
+}
+
+/// Cells in `a` that are not in `b`
+pub fn difference(a: &Tile, b: &Tile) -> Tile {
+    let b: HashSet<_> = b.iter().collect();
+    let cells: BTreeSet<_> = a.iter().filter(|pos| !b.contains(pos)).cloned().collect();
+    cells.into_iter().collect()
+}
+
+/// Cells in exactly one of `a` and `b`, useful to find where two images differ
+pub fn symmetric_difference(a: &Tile, b: &Tile) -> Tile {
+    let set_a: HashSet<_> = a.iter().collect();
+    let set_b: HashSet<_> = b.iter().collect();
+    let cells: BTreeSet<_> = set_a
+        .symmetric_difference(&set_b)
+        .cloned()
+        .cloned()
+        .collect();
+    cells.into_iter().collect()
+}
@@ -0,0 +1,38 @@
+use crate::op::orientations;
+use crate::transform::normalize;
+use crate::Tile;
+
+/// Whether some rotation or flip of `a` has the same shape as `b`
+pub fn symmetry_equal(a: &Tile, b: &Tile) -> bool {
+    let b = normalize(b);
+    orientations(a).any(|oriented| normalize(&oriented) == b)
+}
+
+/// The smallest of the normalized orientations of the tile. Tiles that are equal under rotations
+/// and flips have the same canonical form, so it can be used as a key for their shape.
+pub fn canonical(tile: &Tile) -> Tile {
+    orientations(tile)
+        .map(|oriented| normalize(&oriented))
+        .min()
+        .unwrap()
+}
+
+/// A hash of the tile's shape, computed with FNV-1a over its normalized cells so it is the same
+/// in every run and platform. It may change between versions of this crate and it is not meant
+/// to be cryptographically secure. Combine it with `canonical` to ignore the orientation.
+pub fn tile_hash(tile: &Tile) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    normalize(tile)
+        .iter()
+        .flat_map(|&(x, y)| {
+            let mut bytes = [0_u8; 16];
+            bytes[..8].copy_from_slice(&(x as u64).to_le_bytes());
+            bytes[8..].copy_from_slice(&(y as u64).to_le_bytes());
+            bytes
+        })
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+}
@@ -0,0 +1,201 @@
+use crate::border::{edges, edges_canonical};
+use crate::op::{orientations, Flip, Operation, Rotate};
+use crate::set::{difference, intersection, symmetric_difference, union};
+use crate::transform::{crop, invert, normalize, pad, scale, strip_border, translate, transpose};
+use crate::Position;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+/// The set cells of a tile or image. It derefs to the list of cells, and the transformations,
+/// edges and set operations of the other modules are also available as methods so they can be
+/// chained. The list holds every cell once, repeated cells are dropped when the tile is built,
+/// and may hold them in any order, but tiles are compared, ordered and hashed as sets of cells.
+#[derive(Debug, Clone, Default)]
+pub struct Tile(Vec<Position>);
+
+impl Tile {
+    pub fn new() -> Self {
+        Tile(Vec::new())
+    }
+
+    /// Sorts the cells in place, which does not change the tile but lets it be compared
+    /// without copying them
+    pub fn sort_unstable(&mut self) {
+        self.0.sort_unstable();
+    }
+
+    /// Moves every cell with `f`, which has to be one to one so that no cell gets repeated
+    pub(crate) fn map_cells(&mut self, mut f: impl FnMut(Position) -> Position) {
+        for pos in self.0.iter_mut() {
+            *pos = f(*pos);
+        }
+    }
+
+    /// The cells sorted and without repetitions, only copied if they are not already
+    fn sorted_cells(&self) -> Cow<'_, [Position]> {
+        if self.0.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Cow::Borrowed(&self.0);
+        }
+        let mut cells = self.0.clone();
+        cells.sort_unstable();
+        cells.dedup();
+        Cow::Owned(cells)
+    }
+
+    /// A quarter turn, clockwise when painted
+    pub fn rotate(&self) -> Self {
+        Rotate::One.operate_clone(self)
+    }
+
+    /// Mirrors the tile left to right
+    pub fn flip_h(&self) -> Self {
+        Flip.operate_clone(self)
+    }
+
+    pub fn transpose(&self) -> Self {
+        transpose(self)
+    }
+
+    pub fn normalize(&self) -> Self {
+        normalize(self)
+    }
+
+    pub fn translate(&self, dx: i64, dy: i64) -> Self {
+        translate(self, dx, dy)
+    }
+
+    pub fn crop(&self, min: Position, max: Position) -> Self {
+        crop(self, min, max)
+    }
+
+    pub fn strip_border(&self) -> Self {
+        strip_border(self)
+    }
+
+    pub fn scale(&self, factor: usize) -> Self {
+        scale(self, factor)
+    }
+
+    pub fn invert(&self) -> Self {
+        invert(self)
+    }
+
+    pub fn pad(&self, top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        pad(self, top, right, bottom, left)
+    }
+
+    pub fn union(&self, other: &Tile) -> Self {
+        union(self, other)
+    }
+
+    pub fn intersection(&self, other: &Tile) -> Self {
+        intersection(self, other)
+    }
+
+    pub fn difference(&self, other: &Tile) -> Self {
+        difference(self, other)
+    }
+
+    pub fn symmetric_difference(&self, other: &Tile) -> Self {
+        symmetric_difference(self, other)
+    }
+
+    pub fn edges(&self) -> Option<[u16; 4]> {
+        edges(self)
+    }
+
+    pub fn edges_canonical(&self) -> Option<[u16; 4]> {
+        edges_canonical(self)
+    }
+
+    pub fn orientations(&self) -> impl Iterator<Item = Tile> + '_ {
+        orientations(self)
+    }
+}
+
+impl Deref for Tile {
+    type Target = [Position];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<Position>> for Tile {
+    /// Keeps the first of repeated cells, without copying when the cells are already sorted
+    fn from(cells: Vec<Position>) -> Self {
+        if cells.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Tile(cells);
+        }
+        let mut seen = HashSet::with_capacity(cells.len());
+        Tile(cells.into_iter().filter(|&pos| seen.insert(pos)).collect())
+    }
+}
+
+impl PartialEq for Tile {
+    fn eq(&self, other: &Tile) -> bool {
+        self.sorted_cells() == other.sorted_cells()
+    }
+}
+
+impl Eq for Tile {}
+
+impl PartialEq<Vec<Position>> for Tile {
+    fn eq(&self, other: &Vec<Position>) -> bool {
+        self.sorted_cells() == Tile::from(other.clone()).sorted_cells()
+    }
+}
+
+impl Hash for Tile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sorted_cells().hash(state)
+    }
+}
+
+impl PartialOrd for Tile {
+    fn partial_cmp(&self, other: &Tile) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tile {
+    fn cmp(&self, other: &Tile) -> Ordering {
+        self.sorted_cells().cmp(&other.sorted_cells())
+    }
+}
+
+impl FromIterator<Position> for Tile {
+    fn from_iter<I: IntoIterator<Item = Position>>(iter: I) -> Self {
+        Tile::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl Extend<Position> for Tile {
+    fn extend<I: IntoIterator<Item = Position>>(&mut self, iter: I) {
+        let mut seen: HashSet<Position> = self.0.iter().cloned().collect();
+        self.0
+            .extend(iter.into_iter().filter(|&pos| seen.insert(pos)))
+    }
+}
+
+impl IntoIterator for Tile {
+    type Item = Position;
+    type IntoIter = std::vec::IntoIter<Position>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Tile {
+    type Item = &'a Position;
+    type IntoIter = std::slice::Iter<'a, Position>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Tile;
+use std::collections::{HashMap, HashSet};
+
+/// Moves the tile so it touches both axes, with its cells sorted and without repetitions. Two
+/// tiles with the same shape are equal once normalized.
+pub fn normalize(tile: &Tile) -> Tile {
+    let min_x = tile.iter().map(|pos| pos.0).min().unwrap_or(0);
+    let min_y = tile.iter().map(|pos| pos.1).min().unwrap_or(0);
+    let mut normalized: Tile = tile.iter().map(|&(x, y)| (x - min_x, y - min_y)).collect();
+    normalized.sort_unstable();
+    normalized
+}
+
+/// Shifts every cell by `(dx, dy)`, the caller must make sure no coordinate becomes negative.
+pub fn translate(tile: &Tile, dx: i64, dy: i64) -> Tile {
+    tile.iter()
+        .map(|&(x, y)| ((x as i64 + dx) as usize, (y as i64 + dy) as usize))
+        .collect()
+}
+
+/// Keeps the cells inside the inclusive rectangle `min..=max`, moved so `min` becomes the origin.
+pub fn crop(tile: &Tile, min: (usize, usize), max: (usize, usize)) -> Tile {
+    tile.iter()
+        .filter(|&&(x, y)| min.0 <= x && x <= max.0 && min.1 <= y && y <= max.1)
+        .map(|&(x, y)| (x - min.0, y - min.1))
+        .collect()
+}
+
+/// Removes the outermost row and column on every side of the tile.
+pub fn strip_border(tile: &Tile) -> Tile {
+    let max_x = tile.iter().map(|pos| pos.0).max().unwrap_or(0);
+    let max_y = tile.iter().map(|pos| pos.1).max().unwrap_or(0);
+    if max_x < 2 || max_y < 2 {
+        return Tile::new();
+    }
+    crop(tile, (1, 1), (max_x - 1, max_y - 1))
+}
+
+/// Turns every cell into a `factor` x `factor` block of cells. A factor of 0 is treated as 1.
+pub fn scale(tile: &Tile, factor: usize) -> Tile {
+    let factor = factor.max(1);
+    tile.iter()
+        .flat_map(|&(x, y)| {
+            (0..factor)
+                .flat_map(move |dy| (0..factor).map(move |dx| (x * factor + dx, y * factor + dy)))
+        })
+        .collect()
+}
+
+/// Stitches tiles keyed by their position in the puzzle grid into a single tile. The tiles are
+/// expected to be already oriented and without borders, so each one is `tile_size` wide.
+pub fn assemble(placed: &HashMap<(usize, usize), Tile>, tile_size: usize) -> Tile {
+    let mut image: Tile = placed
+        .iter()
+        .flat_map(|(&(grid_x, grid_y), tile)| {
+            tile.iter()
+                .map(move |&(x, y)| (grid_x * tile_size + x, grid_y * tile_size + y))
+        })
+        .collect();
+    image.sort_unstable();
+    image
+}
+
+/// Mirrors the tile over its main diagonal, swapping the coordinates of every cell
+pub fn transpose(tile: &Tile) -> Tile {
+    let swapped: Tile = tile.iter().map(|&(x, y)| (y, x)).collect();
+    normalize(&swapped)
+}
+
+/// The empty cells inside the tile's bounding box, sorted. An empty tile has no bounding box so
+/// its inversion is empty too.
+pub fn invert(tile: &Tile) -> Tile {
+    let (min_x, max_x, min_y, max_y) = match (
+        tile.iter().map(|pos| pos.0).min(),
+        tile.iter().map(|pos| pos.0).max(),
+        tile.iter().map(|pos| pos.1).min(),
+        tile.iter().map(|pos| pos.1).max(),
+    ) {
+        (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) => (min_x, max_x, min_y, max_y),
+        _ => return Tile::new(),
+    };
+    let cells: HashSet<_> = tile.iter().collect();
+    (min_x..=max_x)
+        .flat_map(|x| (min_y..=max_y).map(move |y| (x, y)))
+        .filter(|pos| !cells.contains(pos))
+        .collect()
+}
+
+/// Leaves `top` empty rows and `left` empty columns before the tile. A tile only holds its set
+/// cells, so the `right` and `bottom` margins are left to whoever renders it with a fixed size.
+pub fn pad(tile: &Tile, top: usize, _right: usize, _bottom: usize, left: usize) -> Tile {
+    translate(tile, left as i64, top as i64)
+}
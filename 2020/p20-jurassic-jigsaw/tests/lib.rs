@@ -0,0 +1,772 @@
+extern crate p20_jurassic_jigsaw;
+use grid::render_set;
+use p20_jurassic_jigsaw::border::{
+    edges, edges_canonical, find_matching_edge, reverse_edge, Border,
+};
+use p20_jurassic_jigsaw::components::{boundary, components, count_components, flood};
+use p20_jurassic_jigsaw::dense::{from_grid, to_grid, DenseTile};
+use p20_jurassic_jigsaw::export::tile_to_svg;
+use p20_jurassic_jigsaw::monster::{find_monsters, sea_monster, water_roughness};
+use p20_jurassic_jigsaw::paint::{
+    paint_gallery, paint_stack, paint_tile_highlighted, paint_tile_styled, render_tile, PaintStyle,
+};
+use p20_jurassic_jigsaw::parse::{
+    load_tile, parse_tile, parse_tile_file, parse_tiles, save_tile, tile_to_string, ParseError,
+    SAVED_TILE_ID,
+};
+use p20_jurassic_jigsaw::puzzle::{
+    adjacency, assemble_puzzle, find_corners, solution, solve, SolveError,
+};
+use p20_jurassic_jigsaw::set::{difference, intersection, symmetric_difference, union};
+use p20_jurassic_jigsaw::symmetry::{canonical, symmetry_equal, tile_hash};
+use p20_jurassic_jigsaw::transform::{
+    assemble, crop, invert, normalize, pad, scale, strip_border, translate, transpose,
+};
+use p20_jurassic_jigsaw::Tile;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "image")]
+#[test]
+fn test_write_png() {
+    use p20_jurassic_jigsaw::export::write_png;
+
+    let tile = Tile::from(vec![(0, 0), (2, 1)]);
+    let path = std::env::temp_dir().join("p20_test_write_png.png");
+    write_png(&tile, 3, &path).unwrap();
+
+    let img = image::open(&path).unwrap().to_luma8();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(img.dimensions(), (9, 6));
+    assert_eq!(img.get_pixel(0, 0).0, [255]);
+    assert_eq!(img.get_pixel(2, 2).0, [255]);
+    assert_eq!(img.get_pixel(3, 0).0, [0]);
+    assert_eq!(img.get_pixel(6, 3).0, [255]);
+    assert_eq!(img.get_pixel(8, 5).0, [255]);
+}
+
+#[test]
+fn test_tile_to_svg() {
+    let svg = tile_to_svg(&Tile::from(vec![(0, 0), (2, 1)]), 4);
+    assert_eq!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"8\" viewBox=\"0 0 12 8\">
+<rect x=\"0\" y=\"0\" width=\"4\" height=\"4\"/>
+<rect x=\"8\" y=\"4\" width=\"4\" height=\"4\"/>
+</svg>
+"
+    );
+
+    assert_eq!(tile_to_svg(&Tile::new(), 4).matches("<rect").count(), 0);
+}
+
+#[test]
+fn test_paint_tile_with_border() {
+    let tile = Tile::from(vec![(0, 0), (1, 1)]);
+    assert_eq!(
+        paint_tile_styled(&tile, &PaintStyle::default()),
+        "██  \n  ██\n"
+    );
+    assert_eq!(
+        paint_tile_styled(&tile, &PaintStyle::default().with_border()),
+        "┌────┐\n│██  │\n│  ██│\n└────┘\n"
+    );
+
+    let style = PaintStyle {
+        cell_width: 1,
+        set: '#',
+        empty: '.',
+        border: true,
+        ..PaintStyle::default()
+    };
+    assert_eq!(paint_tile_styled(&tile, &style), "┌──┐\n│#.│\n│.#│\n└──┘\n");
+}
+
+#[test]
+fn test_translate() {
+    let tile = Tile::from(vec![(0, 0), (3, 1)]);
+    assert_eq!(translate(&tile, 2, 5), Tile::from(vec![(2, 5), (5, 6)]));
+    assert_eq!(translate(&translate(&tile, 2, 5), -2, -5), tile);
+}
+
+#[test]
+fn test_crop() {
+    let tile = Tile::from(vec![(0, 0), (1, 1), (2, 2), (3, 1)]);
+    assert_eq!(
+        crop(&tile, (1, 1), (2, 2)),
+        Tile::from(vec![(0, 0), (1, 1)])
+    );
+    assert_eq!(
+        crop(&tile, (1, 0), (3, 1)),
+        Tile::from(vec![(0, 1), (2, 1)])
+    );
+}
+
+#[test]
+fn test_strip_border() {
+    let full: Tile = (0..10).flat_map(|y| (0..10).map(move |x| (x, y))).collect();
+    let stripped = strip_border(&full);
+    assert_eq!(stripped.len(), 64);
+    assert_eq!(stripped.iter().map(|pos| pos.0).max(), Some(7));
+    assert_eq!(stripped.iter().map(|pos| pos.1).max(), Some(7));
+
+    let data = std::fs::read_to_string("data/test1.txt").unwrap();
+    let (_, tile) = parse_tile(data.split("\n\n").next().unwrap()).unwrap();
+    let stripped = strip_border(&tile);
+    assert!(stripped.iter().all(|&(x, y)| x < 8 && y < 8));
+    assert!(stripped
+        .iter()
+        .all(|&(x, y)| tile.contains(&(x + 1, y + 1))));
+    assert_eq!(
+        stripped.len(),
+        tile.iter()
+            .filter(|&&(x, y)| (1..9).contains(&x) && (1..9).contains(&y))
+            .count()
+    );
+}
+
+#[test]
+fn test_scale() {
+    let tile = Tile::from(vec![(0, 0), (1, 1)]);
+    let mut scaled = scale(&tile, 2);
+    scaled.sort_unstable();
+    assert_eq!(
+        scaled,
+        vec![
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (2, 2),
+            (2, 3),
+            (3, 2),
+            (3, 3)
+        ]
+    );
+    assert_eq!(scale(&tile, 1), tile);
+    assert_eq!(scale(&tile, 0), tile);
+}
+
+#[test]
+fn test_set_operations() {
+    let a = Tile::from(vec![(2, 0), (0, 0), (1, 1)]);
+    let b = Tile::from(vec![(1, 1), (3, 3), (1, 1)]);
+    assert_eq!(
+        union(&a, &b),
+        Tile::from(vec![(0, 0), (1, 1), (2, 0), (3, 3)])
+    );
+    assert_eq!(intersection(&a, &b), Tile::from(vec![(1, 1)]));
+    assert_eq!(difference(&a, &b), Tile::from(vec![(0, 0), (2, 0)]));
+    assert_eq!(difference(&b, &a), Tile::from(vec![(3, 3)]));
+    assert_eq!(intersection(&a, &Tile::new()), vec![]);
+}
+
+#[test]
+fn test_edges() {
+    let data = std::fs::read_to_string("data/test1.txt").unwrap();
+    let (tile_id, tile) = parse_tile(data.split("\n\n").next().unwrap()).unwrap();
+    assert_eq!(tile_id, 2311);
+    assert_eq!(edges(&tile), Some([210, 89, 231, 498]));
+    assert_eq!(edges_canonical(&tile), Some([210, 89, 231, 318]));
+    assert_eq!(edges(&Tile::from(vec![(0, 0), (10, 3)])), None);
+    assert_eq!(find_matching_edge(&Tile::from(vec![(12, 0)]), &tile), None);
+    assert_eq!(reverse_edge(0b0000000001), 0b1000000000);
+    assert_eq!(reverse_edge(reverse_edge(210)), 210);
+}
+
+#[test]
+fn test_find_matching_edge() {
+    let tiles = parse_tile_file("data/test1.txt");
+    let tile = &tiles[&2311];
+    assert_eq!(
+        find_matching_edge(tile, &tiles[&1951]),
+        Some((Border::L, Border::R, false))
+    );
+    assert_eq!(
+        find_matching_edge(tile, &tiles[&1427]),
+        Some((Border::U, Border::D, false))
+    );
+    assert_eq!(
+        find_matching_edge(tile, &tiles[&3079]),
+        Some((Border::R, Border::L, true))
+    );
+    assert_eq!(find_matching_edge(tile, &tiles[&2971]), None);
+}
+
+#[test]
+fn test_assemble() {
+    let mut placed = HashMap::new();
+    placed.insert((0, 0), Tile::from(vec![(0, 0), (1, 1)]));
+    placed.insert((1, 0), Tile::from(vec![(1, 0)]));
+    placed.insert((0, 1), Tile::from(vec![(0, 1)]));
+    assert_eq!(
+        assemble(&placed, 2),
+        Tile::from(vec![(0, 0), (0, 3), (1, 1), (3, 0)])
+    );
+    assert_eq!(assemble(&HashMap::new(), 2), vec![]);
+}
+
+/// The assembled image of the puzzle example, without the tile borders
+fn example_image() -> Tile {
+    // the tiles are already in place but still have their borders
+    let data = std::fs::read_to_string("data/mega_tile_test.txt").unwrap();
+    let (_, with_borders) = parse_tile(&data).unwrap();
+    let mut placed = HashMap::new();
+    for grid_y in 0..3 {
+        for grid_x in 0..3 {
+            let min = (grid_x * 10, grid_y * 10);
+            let tile = crop(&with_borders, min, (min.0 + 9, min.1 + 9));
+            placed.insert((grid_x, grid_y), strip_border(&tile));
+        }
+    }
+    assemble(&placed, 8)
+}
+
+#[test]
+fn test_find_monsters() {
+    let image = example_image();
+    let monster = sea_monster();
+    assert_eq!(monster.len(), 15);
+
+    let found = find_monsters(&image, &monster);
+    assert_eq!(found.positions.len(), 2);
+    assert_eq!(found.cells.len(), 30);
+    assert_eq!(found.image.len() - found.cells.len(), 273);
+
+    let found = find_monsters(&monster, &monster);
+    assert_eq!(found.positions, vec![(0, 0)]);
+    assert_eq!(found.image, monster);
+
+    assert!(find_monsters(&Tile::from(vec![(0, 0)]), &monster)
+        .positions
+        .is_empty());
+
+    // the monster is wider than tall, so the rotated image only fits it once turned back
+    let rotated = monster.rotate();
+    let found = find_monsters(&rotated, &monster);
+    assert_eq!(found.positions, vec![(0, 0)]);
+    assert_eq!(found.cells.len(), 15);
+    assert_eq!(water_roughness(&rotated, &monster), 0);
+}
+
+#[test]
+fn test_paint_tile_highlighted() {
+    let style = PaintStyle {
+        cell_width: 1,
+        set: '#',
+        empty: '.',
+        ..PaintStyle::default()
+    };
+    let water = Tile::from(vec![(0, 0), (1, 0), (2, 1)]);
+    let monster = Tile::from(vec![(1, 0), (2, 1)]);
+    assert_eq!(
+        paint_tile_highlighted(&water, &monster, &style),
+        "#O.\n..O\n"
+    );
+    assert_eq!(
+        paint_tile_highlighted(&water, &Tile::new(), &style),
+        "##.\n..#\n"
+    );
+}
+
+#[test]
+fn test_water_roughness() {
+    let monster = sea_monster();
+    assert_eq!(water_roughness(&example_image(), &monster), 273);
+
+    // two overlapping monsters share their cells
+    let mut image = monster.clone();
+    image.extend(monster.iter().map(|(x, y)| (x + 1, *y)));
+    image.extend(Some((30, 30)));
+    let found = find_monsters(&image, &monster);
+    assert_eq!(found.positions.len(), 2);
+    assert_eq!(water_roughness(&image, &monster), 1);
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(
+        normalize(&Tile::from(vec![(3, 5), (2, 7), (3, 5)])),
+        Tile::from(vec![(0, 2), (1, 0)])
+    );
+    assert_eq!(normalize(&Tile::new()), vec![]);
+}
+
+#[test]
+fn test_symmetry_equal() {
+    // an L shape and the same shape rotated and moved away from the origin
+    let l_shape = Tile::from(vec![(0, 0), (0, 1), (0, 2), (1, 2)]);
+    let rotated = Tile::from(vec![(5, 3), (6, 3), (7, 3), (5, 4)]);
+    assert!(symmetry_equal(&l_shape, &rotated));
+    assert!(symmetry_equal(&rotated, &l_shape));
+    // a flipped L is also reachable
+    assert!(symmetry_equal(
+        &l_shape,
+        &Tile::from(vec![(1, 0), (1, 1), (1, 2), (0, 2)])
+    ));
+    assert!(!symmetry_equal(
+        &l_shape,
+        &Tile::from(vec![(0, 0), (0, 1), (0, 2), (0, 3)])
+    ));
+
+    let square: Tile = (0..3).flat_map(|y| (0..3).map(move |x| (x, y))).collect();
+    assert!(symmetry_equal(&square, &square));
+    assert!(symmetry_equal(&Tile::new(), &Tile::new()));
+}
+
+#[test]
+fn test_canonical() {
+    let l_shape = Tile::from(vec![(0, 0), (0, 1), (0, 2), (1, 2)]);
+    let shapes: HashSet<_> = p20_jurassic_jigsaw::op::orientations(&l_shape)
+        .map(|oriented| canonical(&oriented))
+        .collect();
+    assert_eq!(shapes.len(), 1);
+    assert_eq!(
+        canonical(&Tile::from(vec![(5, 3), (6, 3), (7, 3), (5, 4)])),
+        canonical(&l_shape)
+    );
+    assert_ne!(
+        canonical(&l_shape),
+        canonical(&Tile::from(vec![(0, 0), (0, 1), (0, 2), (0, 3)]))
+    );
+    assert_eq!(canonical(&Tile::new()), vec![]);
+}
+
+#[test]
+fn test_paint_gallery() {
+    let style = PaintStyle {
+        cell_width: 1,
+        set: '#',
+        empty: '.',
+        ..PaintStyle::default()
+    };
+    let small = Tile::from(vec![(0, 0), (1, 1)]);
+    let tall = Tile::from(vec![(0, 0), (0, 1), (0, 2)]);
+    let gallery = paint_gallery(
+        &[("1".to_string(), &small), ("tall".to_string(), &tall)],
+        &style,
+    );
+    assert_eq!(gallery, "1   tall\n#.  #\n.#  #\n    #\n");
+    assert_eq!(paint_gallery(&[], &style), "");
+
+    // the empty cells at the end of the last column are kept so the rows line up
+    let dot = Tile::from(vec![(0, 0)]);
+    let gallery = paint_gallery(
+        &[("a".to_string(), &dot), ("b".to_string(), &small)],
+        &PaintStyle::default(),
+    );
+    assert_eq!(gallery, "a   b\n██  ██  \n      ██\n");
+}
+
+#[test]
+fn test_paint_ruler() {
+    let style = PaintStyle {
+        cell_width: 1,
+        set: '#',
+        empty: '.',
+        ..PaintStyle::default()
+    };
+    let tile = Tile::from(vec![(0, 0), (11, 10)]);
+    let paint = paint_tile_styled(&tile, &style.clone().with_ruler());
+    let lines: Vec<_> = paint.lines().collect();
+    assert_eq!(lines.len(), 13);
+    assert_eq!(lines[0], "   000000000011");
+    assert_eq!(lines[1], "   012345678901");
+    assert_eq!(lines[2], " 0 #...........");
+    assert_eq!(lines[12], "10 ...........#");
+
+    let paint = paint_tile_styled(&Tile::from(vec![(1, 1)]), &style.with_ruler().with_border());
+    assert_eq!(paint, "   01\n  ┌──┐\n0 │..│\n1 │.#│\n  └──┘\n");
+}
+
+#[test]
+fn test_components() {
+    // two diagonal cells and a separate horizontal bar
+    let tile = Tile::from(vec![(0, 0), (1, 1), (3, 0), (4, 0), (5, 0), (4, 0)]);
+    assert_eq!(
+        components(&tile, false),
+        vec![
+            Tile::from(vec![(0, 0)]),
+            Tile::from(vec![(1, 1)]),
+            Tile::from(vec![(3, 0), (4, 0), (5, 0)])
+        ]
+    );
+    assert_eq!(
+        components(&tile, true),
+        vec![
+            Tile::from(vec![(0, 0), (1, 1)]),
+            Tile::from(vec![(3, 0), (4, 0), (5, 0)])
+        ]
+    );
+    assert!(components(&Tile::new(), true).is_empty());
+
+    let monster = sea_monster();
+    assert_eq!(components(&monster, true).len(), 4);
+    assert_eq!(components(&monster, false).len(), 10);
+}
+
+#[test]
+fn test_flood() {
+    let tile = Tile::from(vec![(0, 0), (1, 1), (3, 0), (4, 0), (5, 0)]);
+    assert_eq!(
+        flood(&tile, (4, 0), false),
+        Tile::from(vec![(3, 0), (4, 0), (5, 0)])
+    );
+    assert_eq!(flood(&tile, (1, 1), false), Tile::from(vec![(1, 1)]));
+    assert_eq!(flood(&tile, (1, 1), true), Tile::from(vec![(0, 0), (1, 1)]));
+    assert_eq!(flood(&tile, (2, 0), true), vec![]);
+}
+
+#[test]
+fn test_boundary() {
+    let rectangle: Tile = (2..6).flat_map(|y| (1..8).map(move |x| (x, y))).collect();
+    let mut perimeter: Vec<_> = rectangle
+        .iter()
+        .filter(|&&(x, y)| x == 1 || x == 7 || y == 2 || y == 5)
+        .cloned()
+        .collect();
+    perimeter.sort_unstable();
+    assert_eq!(boundary(&rectangle), perimeter);
+
+    // touching the axes
+    let square: Tile = (0..3).flat_map(|y| (0..3).map(move |x| (x, y))).collect();
+    assert_eq!(boundary(&square).len(), 8);
+    assert!(!boundary(&square).contains(&(1, 1)));
+}
+
+#[test]
+fn test_grid_conversion() {
+    let tile = Tile::from(vec![(0, 1), (2, 0)]);
+    let grid = to_grid(&tile);
+    assert_eq!(
+        grid,
+        vec![vec![false, false, true], vec![true, false, false]]
+    );
+    assert_eq!(from_grid(&grid), tile);
+
+    let image = normalize(&example_image());
+    assert_eq!(from_grid(&to_grid(&image)), image);
+    assert!(to_grid(&Tile::new()).is_empty());
+    assert_eq!(from_grid(&[]), vec![]);
+}
+
+#[test]
+fn test_dense_tile() {
+    let image = normalize(&example_image());
+    let dense = DenseTile::from(&image);
+    assert_eq!((dense.width(), dense.height()), (24, 24));
+    assert!(image.iter().all(|&(x, y)| dense.get(x, y)));
+    assert_eq!(dense.iter().count(), image.len());
+    assert!(!dense.get(24, 0));
+    assert_eq!(Tile::from(dense), image);
+
+    let mut dense = DenseTile::new(70, 2);
+    dense.set(69, 1, true);
+    dense.set(3, 0, true);
+    dense.set(3, 0, false);
+    assert_eq!(Tile::from(&dense), Tile::from(vec![(69, 1)]));
+    assert_eq!(Tile::from(DenseTile::from(&Tile::new())), vec![]);
+}
+
+#[test]
+fn test_save_and_load_tile() {
+    let tile = Tile::from(vec![(0, 1), (2, 0), (2, 1)]);
+    assert_eq!(tile_to_string(&tile), "..#\n#.#\n");
+    assert_eq!(tile_to_string(&Tile::new()), "");
+
+    let path = std::env::temp_dir().join("p20_test_save_and_load_tile.txt");
+    let image = normalize(&example_image());
+    save_tile(&image, &path).unwrap();
+    let mut loaded = load_tile(&path).unwrap();
+    let from_file = parse_tile_file(path.to_str().unwrap());
+    std::fs::write(&path, tile_to_string(&image)).unwrap();
+    let headerless = load_tile(&path);
+    std::fs::remove_file(&path).unwrap();
+    loaded.sort_unstable();
+    assert_eq!(loaded, image);
+    assert_eq!(from_file.len(), 1);
+    assert_eq!(from_file[&SAVED_TILE_ID], loaded);
+    assert_eq!(
+        headerless.unwrap_err().kind(),
+        std::io::ErrorKind::InvalidData
+    );
+
+    assert!(load_tile(&path).is_err());
+}
+
+#[test]
+fn test_transpose() {
+    assert_eq!(
+        transpose(&Tile::from(vec![(3, 0), (0, 1)])),
+        Tile::from(vec![(0, 3), (1, 0)])
+    );
+    let image = normalize(&example_image());
+    assert_ne!(transpose(&image), image);
+    assert_eq!(transpose(&transpose(&image)), image);
+}
+
+#[test]
+fn test_invert() {
+    // the bounding box goes from (1, 1) to (3, 2)
+    let tile = Tile::from(vec![(1, 1), (3, 2), (2, 1)]);
+    assert_eq!(invert(&tile), Tile::from(vec![(1, 2), (2, 2), (3, 1)]));
+    let square: Tile = (0..3).flat_map(|y| (0..3).map(move |x| (x, y))).collect();
+    assert_eq!(invert(&square), vec![]);
+    assert_eq!(invert(&Tile::new()), vec![]);
+}
+
+#[test]
+fn test_pad() {
+    let tile = Tile::from(vec![(0, 0), (1, 2)]);
+    assert_eq!(pad(&tile, 1, 5, 5, 3), Tile::from(vec![(3, 1), (4, 3)]));
+    assert_eq!(pad(&tile, 0, 0, 0, 0), tile);
+}
+
+#[test]
+fn test_symmetric_difference() {
+    let a = Tile::from(vec![(2, 0), (0, 0), (1, 1)]);
+    let b = Tile::from(vec![(1, 1), (3, 3), (1, 1)]);
+    assert_eq!(
+        symmetric_difference(&a, &b),
+        Tile::from(vec![(0, 0), (2, 0), (3, 3)])
+    );
+    assert_eq!(symmetric_difference(&a, &a), vec![]);
+    assert_eq!(
+        symmetric_difference(&a, &Tile::new()),
+        Tile::from(vec![(0, 0), (1, 1), (2, 0)])
+    );
+}
+
+#[test]
+fn test_tile_methods() {
+    let l_shape = Tile::from(vec![(0, 0), (0, 1), (0, 2), (1, 2)]);
+    assert_eq!(
+        l_shape.rotate().normalize(),
+        vec![(0, 0), (0, 1), (1, 0), (2, 0)]
+    );
+    assert_eq!(
+        l_shape.rotate().rotate().rotate().rotate().normalize(),
+        l_shape
+    );
+    assert_eq!(
+        l_shape.flip_h().normalize(),
+        vec![(0, 2), (1, 0), (1, 1), (1, 2)]
+    );
+    assert_eq!(l_shape.flip_h().flip_h(), l_shape);
+    assert_eq!(l_shape.transpose(), transpose(&l_shape));
+    assert_eq!(l_shape.orientations().count(), 8);
+    assert_eq!(l_shape.scale(2), scale(&l_shape, 2));
+    assert_eq!(l_shape.invert(), invert(&l_shape));
+    assert_eq!(l_shape.pad(1, 0, 0, 2), pad(&l_shape, 1, 0, 0, 2));
+    let bar = Tile::from(vec![(0, 2), (1, 2), (2, 2)]);
+    assert_eq!(l_shape.union(&bar), union(&l_shape, &bar));
+    assert_eq!(l_shape.intersection(&bar), intersection(&l_shape, &bar));
+    assert_eq!(l_shape.difference(&bar), difference(&l_shape, &bar));
+    assert_eq!(
+        l_shape.symmetric_difference(&bar),
+        symmetric_difference(&l_shape, &bar)
+    );
+
+    // it still works as the list of cells
+    assert!(l_shape.contains(&(1, 2)));
+    assert_eq!(l_shape.iter().count(), 4);
+
+    // but it compares and hashes as a set of cells
+    let shuffled = Tile::from(vec![(1, 2), (0, 0), (0, 2), (0, 1), (0, 0)]);
+    assert_eq!(shuffled, l_shape);
+    assert_eq!(shuffled.len(), l_shape.len());
+    let mut extended = l_shape.clone();
+    extended.extend(vec![(0, 0), (5, 5), (5, 5)]);
+    assert_eq!(extended.len(), 5);
+    let collected: Tile = l_shape.iter().chain(l_shape.iter()).cloned().collect();
+    assert_eq!(collected.len(), 4);
+    assert_eq!(shuffled, vec![(0, 2), (1, 2), (0, 1), (0, 0)]);
+    assert_eq!(shuffled.cmp(&l_shape), std::cmp::Ordering::Equal);
+    let set: HashSet<Tile> = vec![l_shape.clone(), shuffled].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_ne!(l_shape, Tile::from(vec![(0, 0), (0, 1), (0, 2)]));
+}
+
+#[test]
+fn test_paint_stack() {
+    let style = PaintStyle {
+        cell_width: 1,
+        set: '#',
+        empty: '.',
+        ..PaintStyle::default()
+    };
+    let wide = Tile::from(vec![(0, 0), (2, 0)]);
+    let small = Tile::from(vec![(0, 1)]);
+    assert_eq!(paint_stack(&[&wide, &small], &style), "#.#\n---\n.\n#\n");
+    assert_eq!(paint_stack(&[&small], &style), ".\n#\n");
+    assert_eq!(paint_stack(&[], &style), "");
+
+    let orientations: Vec<Tile> = sea_monster().orientations().collect();
+    let stack = paint_stack(&orientations.iter().collect::<Vec<_>>(), &style);
+    assert_eq!(stack.matches("--------------------\n").count(), 7);
+}
+
+#[test]
+fn test_parse_tiles() {
+    let data = std::fs::read_to_string("data/test1.txt").unwrap();
+    let tiles = parse_tiles(&data).unwrap();
+    assert_eq!(tiles.len(), 9);
+    assert_eq!(tiles[&2311].len(), 48);
+
+    let crlf = "Tile 1:\r\n#.\r\n.#\r\n\r\nTile 22:\r\n##\r\n\r\n\r\n";
+    let tiles = parse_tiles(crlf).unwrap();
+    assert_eq!(tiles.len(), 2);
+    assert_eq!(tiles[&1], vec![(0, 0), (1, 1)]);
+    assert_eq!(tiles[&22], vec![(0, 0), (1, 0)]);
+    assert!(parse_tiles("").unwrap().is_empty());
+
+    // any run of blank lines separates tiles
+    let tiles = parse_tiles("\n\nTile 1:\n#\n\n\n\nTile 2:\n.#\n").unwrap();
+    assert_eq!(tiles.len(), 2);
+    assert_eq!(tiles[&2], vec![(1, 0)]);
+
+    // ids over u32 fit
+    let tiles = parse_tiles("Tile 5000000000:\n#").unwrap();
+    assert!(tiles.contains_key(&5_000_000_000));
+
+    assert_eq!(
+        parse_tiles("Tile 1:\n#\n\nTile x:\n#"),
+        Err(ParseError::BadHeader("Tile x:".to_string()))
+    );
+    assert_eq!(
+        parse_tile("#.#"),
+        Err(ParseError::BadHeader("#.#".to_string()))
+    );
+    assert_eq!(parse_tile(""), Err(ParseError::MissingHeader));
+}
+
+#[test]
+fn test_find_corners() {
+    let tiles = parse_tile_file("data/test1.txt");
+    let corners = find_corners(&tiles);
+    assert_eq!(corners, vec![1171, 1951, 2971, 3079]);
+    assert_eq!(corners.iter().product::<u64>(), 20899048083289);
+
+    let corners = find_corners(&parse_tile_file("data/input1.txt"));
+    assert_eq!(corners.iter().product::<u64>(), 7492183537913);
+
+    // a single tile has four unmatched edges, so it is no corner
+    let mut tiles = tiles;
+    tiles.retain(|&id, _| id == 2311);
+    assert!(find_corners(&tiles).is_empty());
+}
+
+#[test]
+fn test_adjacency() {
+    let tiles = parse_tile_file("data/test1.txt");
+    let adjacency = adjacency(&tiles);
+    assert_eq!(adjacency.len(), 9);
+    assert_eq!(
+        adjacency[&2311],
+        vec![(Border::U, 1427), (Border::R, 3079), (Border::L, 1951)]
+    );
+    assert_eq!(adjacency[&1951], vec![(Border::U, 2729), (Border::R, 2311)]);
+
+    let mut sizes: Vec<usize> = adjacency.values().map(|n| n.len()).collect();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![2, 2, 2, 2, 3, 3, 3, 3, 4]);
+    for corner in find_corners(&tiles) {
+        assert_eq!(adjacency[&corner].len(), 2);
+    }
+}
+
+#[test]
+fn test_assemble_puzzle() {
+    let image = assemble_puzzle(&parse_tile_file("data/test1.txt")).unwrap();
+    assert!(symmetry_equal(&image, &example_image()));
+}
+
+#[test]
+fn test_solve() {
+    let data = std::fs::read_to_string("data/test1.txt").unwrap();
+    assert_eq!(solve(&data), Ok((20899048083289, 273)));
+
+    let data = std::fs::read_to_string("data/input1.txt").unwrap();
+    assert_eq!(solve(&data), Ok((7492183537913, 2323)));
+
+    assert_eq!(
+        solve("Tile x:\n#"),
+        Err(SolveError::Parse(ParseError::BadHeader(
+            "Tile x:".to_string()
+        )))
+    );
+    assert_eq!(solve("Tile 1:\n#"), Err(SolveError::NoFit));
+}
+
+#[test]
+fn test_solution() {
+    let solution = solution(&std::fs::read_to_string("data/test1.txt").unwrap()).unwrap();
+    assert_eq!((solution.part_1, solution.part_2), (20899048083289, 273));
+    assert_eq!(solution.corners, vec![1171, 1951, 2971, 3079]);
+    assert_eq!(solution.monsters.positions.len(), 2);
+    assert_eq!(solution.monsters.roughness(), solution.part_2);
+    assert!(symmetry_equal(&solution.image, &example_image()));
+    // three by three tiles of ten cells, neighbours sharing their edges
+    assert_eq!(solution.puzzle.iter().map(|pos| pos.0).max(), Some(27));
+    assert_eq!(solution.puzzle.iter().map(|pos| pos.1).max(), Some(27));
+}
+
+#[test]
+fn test_count_components() {
+    let tile = Tile::from(vec![(0, 0), (1, 1), (3, 0), (4, 0), (5, 0)]);
+    assert_eq!(count_components(&tile, false), 3);
+    assert_eq!(count_components(&tile, true), 2);
+    assert_eq!(count_components(&Tile::new(), true), 0);
+
+    let image = example_image();
+    for &eight_connected in &[false, true] {
+        assert_eq!(
+            count_components(&image, eight_connected),
+            components(&image, eight_connected).len()
+        );
+    }
+}
+
+#[test]
+fn test_tile_hash() {
+    let l_shape = Tile::from(vec![(0, 0), (0, 1), (0, 2), (1, 2)]);
+    let moved = Tile::from(vec![(6, 5), (5, 3), (5, 4), (5, 5)]);
+    assert_eq!(tile_hash(&l_shape), tile_hash(&moved));
+    assert_ne!(tile_hash(&l_shape), tile_hash(&l_shape.rotate()));
+    assert_eq!(
+        tile_hash(&canonical(&l_shape)),
+        tile_hash(&canonical(&l_shape.rotate()))
+    );
+    // empty tiles hash to the FNV offset basis
+    assert_eq!(tile_hash(&Tile::new()), 0xcbf2_9ce4_8422_2325);
+}
+
+#[test]
+fn test_render_tile() {
+    assert_eq!(render_tile(&Tile::new(), 1), "");
+    assert_eq!(render_tile(&Tile::new(), 2), "");
+    assert_eq!(
+        paint_tile_styled(&Tile::new(), &PaintStyle::default().with_border()),
+        ""
+    );
+
+    let single = Tile::from(vec![(0, 0)]);
+    assert_eq!(render_tile(&single, 1), "█\n");
+    assert_eq!(render_tile(&single, 2), "██\n");
+
+    let cross = Tile::from(vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]);
+    assert_eq!(render_tile(&cross, 1), " █ \n███\n █ \n");
+    assert_eq!(render_tile(&cross, 2), "  ██  \n██████\n  ██  \n");
+}
+
+#[test]
+fn test_render_set() {
+    // tiles keep being drawn from the origin, exactly as before
+    for tile in &[example_image(), sea_monster(), Tile::from(vec![(3, 2)])] {
+        let cells: HashSet<(i64, i64)> = tile.iter().map(|&(x, y)| (x as i64, y as i64)).collect();
+        assert_eq!(render_set(&cells), render_tile(tile, 2));
+        assert_eq!(
+            render_tile(tile, 2),
+            paint_tile_styled(tile, &PaintStyle::default())
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Text rendering of sparse grids with signed coordinates, shared by the puzzles of every year
+//! that have to draw one
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+/// How a grid is laid out: each cell is `cell_width` chars, missing cells show `background`.
+/// Rows go top to bottom with y growing downwards unless `flip_y`. Everything is drawn unless
+/// `with_max_size` caps the drawn area to `max_width` by `max_height` cells, counted from the
+/// first row and column drawn, so a stray far away cell can not blow up the drawing.
+#[derive(Debug, Clone)]
+pub struct GridStyle {
+    pub cell_width: usize,
+    pub background: char,
+    pub flip_y: bool,
+    /// Stretch the bounding box to the origin, like tiles which are drawn from (0, 0)
+    pub from_origin: bool,
+    pub max_width: Option<usize>,
+    pub max_height: Option<usize>,
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        GridStyle {
+            cell_width: 1,
+            background: ' ',
+            flip_y: false,
+            from_origin: false,
+            max_width: None,
+            max_height: None,
+        }
+    }
+}
+
+impl GridStyle {
+    pub fn with_flip_y(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
+    pub fn with_max_size(mut self, max_width: usize, max_height: usize) -> Self {
+        self.max_width = Some(max_width);
+        self.max_height = Some(max_height);
+        self
+    }
+}
+
+/// Draws the bounding box of the cells one char per cell, an empty grid is an empty string
+pub fn render_grid<T>(cells: &HashMap<(i64, i64), T>, to_char: impl Fn(&T) -> char) -> String {
+    render_grid_styled(cells, to_char, &GridStyle::default())
+}
+
+pub fn render_grid_styled<T>(
+    cells: &HashMap<(i64, i64), T>,
+    to_char: impl Fn(&T) -> char,
+    style: &GridStyle,
+) -> String {
+    render_grid_cells(
+        cells,
+        |cell| {
+            let c = cell.map_or(style.background, &to_char);
+            c.to_string().repeat(style.cell_width)
+        },
+        style,
+    )
+}
+
+/// Same as `render_grid_styled` but each cell is drawn as whatever `to_cell` gives for it,
+/// `None` being a missing cell, so `cell_width` and `background` are not used
+pub fn render_grid_cells<T, S: AsRef<str>>(
+    cells: &HashMap<(i64, i64), T>,
+    to_cell: impl Fn(Option<&T>) -> S,
+    style: &GridStyle,
+) -> String {
+    let first = match cells.keys().next() {
+        Some(&first) => first,
+        None => return String::new(),
+    };
+    let origin = if style.from_origin {
+        Some((0, 0))
+    } else {
+        None
+    };
+    let bounds = cells.keys().cloned().chain(origin).fold(
+        (first, first),
+        |((min_x, min_y), (max_x, max_y)), (x, y)| {
+            ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+        },
+    );
+    render_grid_within(cells, bounds, to_cell, style)
+}
+
+/// Same as `render_grid_cells` but draws the `(min, max)` corners given instead of the bounding
+/// box of the cells, for callers that already keep track of it. `from_origin` is not used
+pub fn render_grid_within<T, S: AsRef<str>>(
+    cells: &HashMap<(i64, i64), T>,
+    ((min_x, min_y), (max_x, max_y)): ((i64, i64), (i64, i64)),
+    to_cell: impl Fn(Option<&T>) -> S,
+    style: &GridStyle,
+) -> String {
+    let width = side(min_x, max_x, style.max_width);
+    let height = side(min_y, max_y, style.max_height);
+
+    let mut paint = String::new();
+    let mut draw_row = |y| {
+        for x in (min_x..=max_x).take(width) {
+            paint.push_str(to_cell(cells.get(&(x, y))).as_ref());
+        }
+        paint.push('\n');
+    };
+    if style.flip_y {
+        (min_y..=max_y).rev().take(height).for_each(&mut draw_row);
+    } else {
+        (min_y..=max_y).take(height).for_each(&mut draw_row);
+    }
+    paint
+}
+
+/// Number of cells in `min..=max`, capped to `max_len`
+fn side(min: i64, max: i64, max_len: Option<usize>) -> usize {
+    let len = usize::try_from(max as i128 - min as i128 + 1).unwrap_or(usize::MAX);
+    max_len.map_or(len, |max_len| len.min(max_len))
+}
+
+/// Draws the cells in the set like the 2020 tiles: from the origin, with full blocks two chars
+/// wide
+pub fn render_set(cells: &HashSet<(i64, i64)>) -> String {
+    let cells: HashMap<(i64, i64), ()> = cells.iter().map(|&pos| (pos, ())).collect();
+    let style = GridStyle {
+        cell_width: 2,
+        from_origin: true,
+        ..GridStyle::default()
+    };
+    render_grid_styled(&cells, |_| '█', &style)
+}
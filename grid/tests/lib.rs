@@ -0,0 +1,117 @@
+extern crate grid;
+use grid::{
+    render_grid, render_grid_cells, render_grid_styled, render_grid_within, render_set, GridStyle,
+};
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn test_render_grid_negative() {
+    let cells: HashMap<(i64, i64), char> = vec![((-2, -1), 'a'), ((0, 0), 'b'), ((1, -1), 'c')]
+        .into_iter()
+        .collect();
+    assert_eq!(render_grid(&cells, |&c| c), "a  c\n  b \n");
+
+    let style = GridStyle {
+        cell_width: 2,
+        background: '.',
+        ..GridStyle::default()
+    };
+    assert_eq!(
+        render_grid_styled(&cells, |&c| c, &style),
+        "aa....cc\n....bb..\n"
+    );
+    assert_eq!(
+        render_grid_styled(&cells, |&c| c, &style.with_max_size(2, 1)),
+        "aa..\n"
+    );
+}
+
+#[test]
+fn test_render_grid_empty() {
+    let cells: HashMap<(i64, i64), char> = HashMap::new();
+    assert_eq!(render_grid(&cells, |&c| c), "");
+    assert_eq!(render_set(&HashSet::new()), "");
+}
+
+#[test]
+fn test_render_grid_flip_y() {
+    let cells: HashMap<(i64, i64), u8> = vec![((0, 0), 1), ((1, 1), 2), ((2, 3), 3)]
+        .into_iter()
+        .collect();
+    let to_char = |&v: &u8| (b'0' + v) as char;
+    assert_eq!(render_grid(&cells, to_char), "1  \n 2 \n   \n  3\n");
+    let style = GridStyle::default().with_flip_y();
+    assert_eq!(
+        render_grid_styled(&cells, to_char, &style),
+        "  3\n   \n 2 \n1  \n"
+    );
+    assert_eq!(
+        render_grid_styled(&cells, to_char, &style.with_max_size(3, 2)),
+        "  3\n   \n"
+    );
+}
+
+#[test]
+fn test_render_grid_huge_bounds() {
+    let to_char = |&v: &u8| (b'0' + v) as char;
+
+    // cells at the edges of the coordinates do not overflow
+    let edge: HashMap<(i64, i64), u8> = vec![((i64::MAX - 1, 0), 1), ((i64::MAX, 1), 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(render_grid(&edge, to_char), "1 \n 2\n");
+
+    // big grids are drawn whole by default
+    let wide: HashMap<(i64, i64), u8> = vec![((0, 0), 1), ((2000, 0), 2)].into_iter().collect();
+    assert_eq!(render_grid(&wide, to_char).len(), 2002);
+
+    // a stray far away cell only has to be capped
+    let far: HashMap<(i64, i64), u8> = vec![((0, 0), 1), ((i64::MAX, i64::MIN), 2)]
+        .into_iter()
+        .collect();
+    let capped = GridStyle::default().with_flip_y().with_max_size(2, 2);
+    assert_eq!(render_grid_styled(&far, to_char, &capped), "1 \n  \n");
+}
+
+#[test]
+fn test_render_grid_cells() {
+    let cells: HashMap<(i64, i64), bool> =
+        vec![((0, 0), true), ((2, -1), false)].into_iter().collect();
+    let to_cell = |cell: Option<&bool>| match cell {
+        Some(true) => "()",
+        Some(false) => "[]",
+        None => "..",
+    };
+    assert_eq!(
+        render_grid_cells(&cells, to_cell, &GridStyle::default()),
+        "....[]\n()....\n"
+    );
+}
+
+#[test]
+fn test_render_grid_within() {
+    let cells: HashMap<(i64, i64), char> = vec![((0, 0), 'a')].into_iter().collect();
+    let to_cell = |cell: Option<&char>| cell.map_or('.', |&c| c).to_string();
+    let style = GridStyle::default();
+    assert_eq!(
+        render_grid_within(&cells, ((-1, 0), (1, 1)), to_cell, &style),
+        ".a.\n...\n"
+    );
+    // cells outside the corners are not drawn
+    assert_eq!(
+        render_grid_within(&cells, ((1, 1), (1, 1)), to_cell, &style),
+        ".\n"
+    );
+}
+
+#[test]
+fn test_render_set() {
+    let set: HashSet<(i64, i64)> = vec![(1, 0), (0, 1)].into_iter().collect();
+    assert_eq!(render_set(&set), "  ██\n██  \n");
+
+    // the drawing starts at the origin
+    let set: HashSet<(i64, i64)> = vec![(2, 1)].into_iter().collect();
+    assert_eq!(render_set(&set), "      \n    ██\n");
+    let set: HashSet<(i64, i64)> = vec![(-1, 1)].into_iter().collect();
+    assert_eq!(render_set(&set), "    \n██  \n");
+}